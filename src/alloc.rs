@@ -0,0 +1,103 @@
+use std::{alloc::{GlobalAlloc, Layout, System}, sync::atomic::{AtomicUsize, Ordering}};
+
+#[global_allocator]
+pub static ALLOCATOR: CountingAllocator = CountingAllocator::new();
+
+pub struct CountingAllocator {
+    current: AtomicUsize,
+    peak: AtomicUsize,
+}
+
+impl CountingAllocator {
+
+    pub const fn new() -> Self {
+        Self { current: AtomicUsize::new(0), peak: AtomicUsize::new(0) }
+    }
+
+    pub fn current(&self) -> usize {
+        self.current.load(Ordering::Relaxed)
+    }
+
+    pub fn peak(&self) -> usize {
+        self.peak.load(Ordering::Relaxed)
+    }
+
+    pub fn reset_peak(&self) -> usize {
+        let current = self.current();
+        self.peak.store(current, Ordering::Relaxed);
+        current
+    }
+
+    fn record_allocation(&self, size: usize) {
+        let current = self.current.fetch_add(size, Ordering::Relaxed) + size;
+        self.peak.fetch_max(current, Ordering::Relaxed);
+    }
+
+    fn record_deallocation(&self, size: usize) {
+        self.current.fetch_sub(size, Ordering::Relaxed);
+    }
+}
+
+impl Default for CountingAllocator {
+
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let pointer = unsafe { System.alloc(layout) };
+        if !pointer.is_null() {
+            self.record_allocation(layout.size());
+        }
+        pointer
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let pointer = unsafe { System.alloc_zeroed(layout) };
+        if !pointer.is_null() {
+            self.record_allocation(layout.size());
+        }
+        pointer
+    }
+
+    unsafe fn dealloc(&self, pointer: *mut u8, layout: Layout) {
+        unsafe { System.dealloc(pointer, layout) };
+        self.record_deallocation(layout.size());
+    }
+
+    unsafe fn realloc(&self, pointer: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_pointer = unsafe { System.realloc(pointer, layout, new_size) };
+        if !new_pointer.is_null() {
+            self.record_deallocation(layout.size());
+            self.record_allocation(new_size);
+        }
+        new_pointer
+    }
+}
+
+#[cfg(test)]
+mod test_alloc {
+    use super::*;
+
+    #[test]
+    fn test_counting_allocator_tracks_peak() {
+        let allocator = CountingAllocator::new();
+        let layout = Layout::from_size_align(1024, 8).unwrap();
+
+        unsafe {
+            let pointer = allocator.alloc(layout);
+            assert_eq!(allocator.current(), 1024);
+            let pointer = allocator.realloc(pointer, layout, 4096);
+            assert_eq!(allocator.current(), 4096);
+            allocator.dealloc(pointer, Layout::from_size_align(4096, 8).unwrap());
+        }
+
+        assert_eq!(allocator.current(), 0);
+        assert_eq!(allocator.peak(), 4096);
+        assert_eq!(allocator.reset_peak(), 0);
+        assert_eq!(allocator.peak(), 0);
+    }
+}
@@ -0,0 +1,384 @@
+use std::{fmt, fs};
+
+use crate::input::input::InputParser;
+use crate::input::source::Source;
+use crate::problems::{self, solution::{Part, Solution}};
+use crate::runner::catch_panic;
+
+pub const ANSWERS_FILE: &str = "answers.toml";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Dataset {
+    Example,
+    Real,
+}
+
+impl Dataset {
+
+    pub fn both() -> [Self; 2] {
+        [Self::Example, Self::Real]
+    }
+
+    pub fn input_file(&self, day: u32) -> String {
+        match self {
+            Self::Example => format!("example_{:02}.txt", day),
+            Self::Real => format!("input_{:02}.txt", day),
+        }
+    }
+}
+
+impl fmt::Display for Dataset {
+
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Example => write!(f, "example"),
+            Self::Real => write!(f, "real"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExpectedAnswer {
+    pub day: u32,
+    pub part: Part,
+    pub example: Option<String>,
+    pub real: Option<String>,
+}
+
+impl ExpectedAnswer {
+
+    pub fn get(&self, dataset: Dataset) -> Option<&str> {
+        match dataset {
+            Dataset::Example => self.example.as_deref(),
+            Dataset::Real => self.real.as_deref(),
+        }
+    }
+}
+
+pub fn is_placeholder(answer: &str) -> bool {
+    answer.trim().is_empty()
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Answers {
+    entries: Vec<ExpectedAnswer>,
+}
+
+impl Answers {
+
+    pub fn load(parser: &InputParser) -> Result<Self, String> {
+        let path = parser.resolve(ANSWERS_FILE).map_err(|error| error.to_string())?;
+        let text = fs::read_to_string(&path).map_err(|error| format!("Unable to read {}: {}.", path.display(), error))?;
+        Self::parse(&text).map_err(|error| format!("{}:{}", path.display(), error))
+    }
+
+    pub fn parse(text: &str) -> Result<Self, String> {
+        let mut entries = vec![];
+        let mut current: Option<(usize, Vec<(String, String)>)> = None;
+        let mut lines = text.lines().enumerate();
+        while let Some((i, raw_line)) = lines.next() {
+            let line = raw_line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            if line == "[[answer]]" {
+                if let Some((start, fields)) = current.take() {
+                    entries.push(Self::entry_from_fields(start, fields)?);
+                }
+                current = Some((i + 1, vec![]));
+                continue;
+            }
+            let (key, value) = line.split_once('=').ok_or(format!("{}: Expected key = value but found {:?}.", i + 1, raw_line))?;
+            let fields = &mut current.as_mut().ok_or(format!("{}: {:?} appears before any [[answer]] table.", i + 1, raw_line))?.1;
+            let value = match value.trim().strip_prefix("'''") {
+                Some(first) => Self::parse_multiline_literal(first, &mut lines),
+                None => Self::parse_value(value.trim()),
+            };
+            fields.push((key.trim().to_string(), value.map_err(|error| format!("{}: {}", i + 1, error))?));
+        }
+        if let Some((start, fields)) = current.take() {
+            entries.push(Self::entry_from_fields(start, fields)?);
+        }
+        Ok(Self { entries })
+    }
+
+    fn parse_multiline_literal<'a, I: Iterator<Item = (usize, &'a str)>>(first: &str, lines: &mut I) -> Result<String, String> {
+        let mut content = first.to_string();
+        let mut started = !first.is_empty();
+        loop {
+            if let Some(end) = content.find("'''") {
+                Self::expect_end_of_value(&content[end + 3..])?;
+                content.truncate(end);
+                return Ok(content);
+            }
+            let (_, line) = lines.next().ok_or("Unterminated multi-line string.")?;
+            if started {
+                content.push('\n');
+            }
+            content.push_str(line);
+            started = true;
+        }
+    }
+
+    fn expect_end_of_value(rest: &str) -> Result<(), String> {
+        let rest = rest.trim();
+        match rest.is_empty() || rest.starts_with('#') {
+            true => Ok(()),
+            false => Err(format!("Unexpected text after string: {:?}.", rest)),
+        }
+    }
+
+    fn parse_value(value: &str) -> Result<String, String> {
+        let value = match value.find(" #") {
+            Some(comment) if !value.starts_with(['"', '\'']) => value[..comment].trim(),
+            _ => value,
+        };
+        if let Some(literal) = value.strip_prefix('\'') {
+            let (content, rest) = literal.split_once('\'').ok_or(format!("Unterminated string {:?}.", value))?;
+            Self::expect_end_of_value(rest)?;
+            Ok(content.to_string())
+        } else if let Some(quoted) = value.strip_prefix('"') {
+            let mut result = String::new();
+            let mut chars = quoted.chars();
+            while let Some(c) = chars.next() {
+                match c {
+                    '"' => {
+                        Self::expect_end_of_value(chars.as_str())?;
+                        return Ok(result);
+                    },
+                    '\\' => match chars.next() {
+                        Some('n') => result.push('\n'),
+                        Some('"') => result.push('"'),
+                        Some('\\') => result.push('\\'),
+                        other => return Err(format!("Unsupported escape sequence \\{}.", other.map(String::from).unwrap_or_default())),
+                    },
+                    _ => result.push(c),
+                }
+            }
+            Err(format!("Unterminated string {:?}.", value))
+        } else if !value.is_empty() && value.chars().all(|c| c.is_ascii_digit() || c == '-') {
+            Ok(value.to_string())
+        } else {
+            Err(format!("Expected a quoted string or integer but found {:?}.", value))
+        }
+    }
+
+    fn entry_from_fields(start: usize, fields: Vec<(String, String)>) -> Result<ExpectedAnswer, String> {
+        let mut day = None;
+        let mut part = None;
+        let mut example = None;
+        let mut real = None;
+        for (key, value) in fields {
+            match key.as_str() {
+                "day" => day = Some(value.parse::<u32>().map_err(|_| format!("{}: Invalid day {:?}.", start, value))?),
+                "part" => part = Some(Part::parse(&value).map_err(|error| format!("{}: {}", start, error))?),
+                "example" => example = Some(value),
+                "real" => real = Some(value),
+                _ => return Err(format!("{}: Unknown key {:?}.", start, key)),
+            }
+        }
+        Ok(ExpectedAnswer {
+            day: day.ok_or(format!("{}: [[answer]] is missing a day.", start))?,
+            part: part.ok_or(format!("{}: [[answer]] is missing a part.", start))?,
+            example,
+            real,
+        })
+    }
+
+    pub fn get(&self, day: u32, part: Part) -> Option<&ExpectedAnswer> {
+        self.entries.iter().find(|entry| entry.day == day && entry.part == part)
+    }
+
+    pub fn entries(&self) -> &[ExpectedAnswer] {
+        &self.entries
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CheckStatus {
+    Passed,
+    Failed { expected: String, actual: String },
+    Crashed(String),
+    Unimplemented,
+    MissingAnswer,
+    PlaceholderAnswer(String),
+    MissingInput(String),
+}
+
+impl CheckStatus {
+
+    pub fn is_failure(&self) -> bool {
+        matches!(self, Self::Failed { .. } | Self::Crashed(_))
+    }
+}
+
+impl fmt::Display for CheckStatus {
+
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Passed => write!(f, "ok"),
+            Self::Failed { expected, actual } => write!(f, "FAILED: expected {} but got {}", expected, actual),
+            Self::Crashed(message) => write!(f, "CRASHED: {}", message),
+            Self::Unimplemented => write!(f, "unimplemented"),
+            Self::MissingAnswer => write!(f, "missing answer in {}", ANSWERS_FILE),
+            Self::PlaceholderAnswer(answer) => write!(f, "placeholder answer {:?} in {}", answer, ANSWERS_FILE),
+            Self::MissingInput(message) => write!(f, "missing input: {}", message),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Check {
+    pub day: u32,
+    pub part: Part,
+    pub dataset: Dataset,
+    pub status: CheckStatus,
+}
+
+pub fn check_solution(solution: &dyn Solution, parser: &InputParser, answers: &Answers) -> Vec<Check> {
+    let mut checks = vec![];
+    for part in Part::both() {
+        for dataset in Dataset::both() {
+            let status = check_part(solution, part, dataset, parser, answers);
+            checks.push(Check { day: solution.day(), part, dataset, status });
+        }
+    }
+    checks
+}
+
+fn check_part(solution: &dyn Solution, part: Part, dataset: Dataset, parser: &InputParser, answers: &Answers) -> CheckStatus {
+    if !solution.implemented(part) {
+        return CheckStatus::Unimplemented;
+    }
+    let expected = match answers.get(solution.day(), part).and_then(|entry| entry.get(dataset)) {
+        None => return CheckStatus::MissingAnswer,
+        Some(answer) if is_placeholder(answer) => return CheckStatus::PlaceholderAnswer(answer.to_string()),
+        Some(answer) => answer.trim().to_string(),
+    };
+    let input = match solution.input_kind().load(parser, Source::file(&dataset.input_file(solution.day()))) {
+        Ok(input) => input,
+        Err(error) => return CheckStatus::MissingInput(error.to_string()),
+    };
+    match catch_panic(|| solution.solve(part, input)) {
+        Ok(actual) if actual.matches(&expected) => CheckStatus::Passed,
+        Ok(actual) => CheckStatus::Failed { expected, actual: actual.to_string() },
+        Err(panic) if panic.is_unimplemented() => CheckStatus::Unimplemented,
+        Err(panic) => CheckStatus::Crashed(panic.to_string()),
+    }
+}
+
+pub fn check_all(parser: &InputParser, answers: &Answers) -> Vec<Check> {
+    problems::all_solutions().iter().flat_map(|solution| check_solution(solution.as_ref(), parser, answers)).collect()
+}
+
+pub fn report(checks: &[Check]) -> String {
+    let mut lines: Vec<String> = checks.iter().map(
+        |check| format!("Day {:02}{} {:<7} {}", check.day, check.part.letter(), check.dataset.to_string(), check.status)
+    ).collect();
+    let count = |f: fn(&CheckStatus) -> bool| checks.iter().filter(|check| f(&check.status)).count();
+    lines.push(format!(
+        "{} passed, {} failed, {} unimplemented, {} missing or placeholder answers, {} missing inputs.",
+        count(|status| *status == CheckStatus::Passed),
+        count(|status| status.is_failure()),
+        count(|status| *status == CheckStatus::Unimplemented),
+        count(|status| matches!(status, CheckStatus::MissingAnswer | CheckStatus::PlaceholderAnswer(_))),
+        count(|status| matches!(status, CheckStatus::MissingInput(_))),
+    ));
+    lines.join("\n")
+}
+
+#[cfg(test)]
+mod test_answers {
+    use super::*;
+    use crate::problems::answer::Answer;
+
+    #[test]
+    fn test_answers_parse_tables() {
+        let answers = Answers::parse("
+            # A comment.
+            [[answer]]
+            day = 6
+            part = \"b\"
+            example = \"26984457539\" # Overflows u32.
+
+            [[answer]]
+            day = 13
+            part = \"a\"
+            real = 17
+        ").unwrap();
+
+        assert_eq!(answers.entries().len(), 2);
+        assert_eq!(answers.get(6, Part::B).unwrap().get(Dataset::Example), Some("26984457539"));
+        assert_eq!(answers.get(6, Part::B).unwrap().get(Dataset::Real), None);
+        assert_eq!(answers.get(13, Part::A).unwrap().get(Dataset::Real), Some("17"));
+        assert!(answers.get(13, Part::B).is_none());
+    }
+
+    #[test]
+    fn test_answers_report_line_of_bad_entries() {
+        let error = Answers::parse("[[answer]]\nday = 1\npart = \"c\"").unwrap_err();
+        assert!(error.starts_with("1:"), "{}", error);
+
+        let error = Answers::parse("[[answer]]\nday = 1\npart = \"a\"\nreal = \"12").unwrap_err();
+        assert!(error.starts_with("4:"), "{}", error);
+
+        let error = Answers::parse("day = 1").unwrap_err();
+        assert!(error.starts_with("1:"), "{}", error);
+
+        let error = Answers::parse("[[answer]]\nday = 13\npart = \"b\"\nreal = '''\n#..#\n####").unwrap_err();
+        assert!(error.starts_with("4:") && error.contains("Unterminated multi-line"), "{}", error);
+    }
+
+    #[test]
+    fn test_answers_parse_literal_strings_for_art() {
+        let answers = Answers::parse("
+            [[answer]]
+            day = 13
+            part = \"b\"
+            example = 'O # with a hash' # Comment.
+            real = '''
+#..#.####
+#..#....#
+####...#.
+#..#..#..
+#..#.#...
+#..#.####
+''' # The letters HZ.
+        ").unwrap();
+
+        let entry = answers.get(13, Part::B).unwrap();
+        assert_eq!(entry.get(Dataset::Example), Some("O # with a hash"));
+        assert_eq!(entry.get(Dataset::Real), Some("#..#.####\n#..#....#\n####...#.\n#..#..#..\n#..#.#...\n#..#.####\n"));
+        assert!(Answer::art(&["#..#.####", "#..#....#", "####...#.", "#..#..#..", "#..#.#...", "#..#.####"]).matches(entry.real.as_deref().unwrap()));
+
+        let answers = Answers::parse("[[answer]]\nday = 1\npart = \"a\"\nreal = '''HZ'''").unwrap();
+        assert_eq!(answers.get(1, Part::A).unwrap().get(Dataset::Real), Some("HZ"));
+    }
+
+    #[test]
+    fn test_placeholders_are_reported_instead_of_checked() {
+        let parser = InputParser::new();
+        let answers = Answers::parse("[[answer]]\nday = 1\npart = \"a\"\nexample = \"7\"\nreal = \" \"\n[[answer]]\nday = 1\npart = \"b\"\nexample = \"0\"").unwrap();
+        let solution = problems::get_solution(1).unwrap();
+        let checks = check_solution(solution.as_ref(), &parser, &answers);
+
+        assert_eq!(checks[0].status, CheckStatus::Passed);
+        assert_eq!(checks[1].status, CheckStatus::PlaceholderAnswer(" ".to_string()));
+        assert_eq!(checks[2].status, CheckStatus::Failed { expected: "0".to_string(), actual: "5".to_string() });
+        assert_eq!(checks[3].status, CheckStatus::MissingAnswer);
+
+        let solution = problems::get_solution(7).unwrap();
+        assert!(check_solution(solution.as_ref(), &parser, &answers).iter().all(|check| check.status == CheckStatus::Unimplemented));
+    }
+
+    #[test]
+    fn test_every_registered_solver_matches_expected_answers() {
+        let parser = InputParser::new();
+        let answers = Answers::load(&parser).unwrap();
+        let checks = check_all(&parser, &answers);
+
+        println!("{}", report(&checks));
+        let failures: Vec<&Check> = checks.iter().filter(|check| check.status.is_failure()).collect();
+        assert!(failures.is_empty(), "{}", report(&failures.into_iter().cloned().collect::<Vec<_>>()));
+    }
+}
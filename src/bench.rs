@@ -0,0 +1,172 @@
+use std::time::{Duration, Instant};
+
+use crate::alloc::ALLOCATOR;
+use crate::output::{format_bytes, format_duration, json_string};
+use crate::problems::solution::{Input, Part, Solution};
+use crate::runner::{catch_panic, Panic};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Timing {
+    pub answer: String,
+    pub iterations: usize,
+    pub min: Duration,
+    pub median: Duration,
+    pub max: Duration,
+    pub peak_bytes: usize,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BenchResult {
+    pub day: u32,
+    pub part: Part,
+    pub outcome: Result<Timing, Panic>,
+}
+
+pub fn bench_part(solution: &dyn Solution, part: Part, input: &Input, iterations: usize) -> BenchResult {
+    assert!(iterations > 0, "Benchmarks need at least one iteration.");
+    let mut durations = Vec::with_capacity(iterations);
+    let mut peak_bytes = 0;
+    let mut answer = String::new();
+    for _ in 0..iterations {
+        let input = input.clone();
+        let baseline = ALLOCATOR.reset_peak();
+        let start = Instant::now();
+        let result = match catch_panic(|| solution.solve(part, input)) {
+            Ok(result) => result,
+            Err(panic) => return BenchResult { day: solution.day(), part, outcome: Err(panic) },
+        };
+        durations.push(start.elapsed());
+        peak_bytes = peak_bytes.max(ALLOCATOR.peak().saturating_sub(baseline));
+        answer = result.to_string();
+    }
+    durations.sort();
+    BenchResult {
+        day: solution.day(),
+        part,
+        outcome: Ok(Timing {
+            answer,
+            iterations,
+            min: durations[0],
+            median: median(&durations),
+            max: durations[durations.len() - 1],
+            peak_bytes,
+        }),
+    }
+}
+
+fn median(sorted: &[Duration]) -> Duration {
+    let middle = sorted.len() / 2;
+    if sorted.len().is_multiple_of(2) {
+        (sorted[middle - 1] + sorted[middle]) / 2
+    } else {
+        sorted[middle]
+    }
+}
+
+pub fn table(results: &[BenchResult]) -> String {
+    let mut lines = vec![format!(
+        "{:<5} {:<4} {:>6} {:>12} {:>12} {:>12} {:>10}  {}",
+        "Day", "Part", "Runs", "Min", "Median", "Max", "Peak", "Answer"
+    )];
+    for result in results {
+        let day = format!("{:02}", result.day);
+        lines.push(match &result.outcome {
+            Ok(timing) => format!(
+                "{:<5} {:<4} {:>6} {:>12} {:>12} {:>12} {:>10}  {}",
+                day,
+                result.part.letter(),
+                timing.iterations,
+                format_duration(timing.min),
+                format_duration(timing.median),
+                format_duration(timing.max),
+                format_bytes(timing.peak_bytes),
+                timing.answer,
+            ),
+            Err(panic) => format!("{:<5} {:<4} {:>6} {:>12} {:>12} {:>12} {:>10}  crashed: {}", day, result.part.letter(), "", "", "", "", "", panic),
+        });
+    }
+    lines.join("\n")
+}
+
+pub fn json(results: &[BenchResult]) -> String {
+    let records: Vec<String> = results.iter().map(|result| match &result.outcome {
+        Ok(timing) => format!(
+            "{{\"day\":{},\"part\":\"{}\",\"status\":\"ok\",\"answer\":{},\"iterations\":{},\"min_ns\":{},\"median_ns\":{},\"max_ns\":{},\"peak_bytes\":{}}}",
+            result.day,
+            result.part.letter(),
+            json_string(&timing.answer),
+            timing.iterations,
+            timing.min.as_nanos(),
+            timing.median.as_nanos(),
+            timing.max.as_nanos(),
+            timing.peak_bytes,
+        ),
+        Err(panic) => format!(
+            "{{\"day\":{},\"part\":\"{}\",\"status\":\"crashed\",\"message\":{},\"location\":{}}}",
+            result.day,
+            result.part.letter(),
+            json_string(&panic.message),
+            panic.location.as_deref().map(json_string).unwrap_or("null".to_string()),
+        ),
+    }).collect();
+    format!("[{}]", records.join(","))
+}
+
+#[cfg(test)]
+mod test_bench {
+    use super::*;
+    use crate::input::{input::InputParser, source::Source};
+    use crate::problems;
+
+    #[test]
+    fn test_median_of_odd_and_even_samples() {
+        let samples = |millis: &[u64]| millis.iter().map(|m| Duration::from_millis(*m)).collect::<Vec<_>>();
+
+        assert_eq!(median(&samples(&[1, 2, 9])), Duration::from_millis(2));
+        assert_eq!(median(&samples(&[1, 2, 4, 9])), Duration::from_millis(3));
+    }
+
+    #[test]
+    fn test_bench_part_records_every_iteration() {
+        let solution = problems::get_solution(6).unwrap();
+        let input = solution.input_kind().load(&InputParser::new(), Source::text("3,4,3,1,2")).unwrap();
+        let timing = bench_part(solution.as_ref(), Part::A, &input, 3).outcome.unwrap();
+
+        assert_eq!(timing.answer, "5934");
+        assert_eq!(timing.iterations, 3);
+        assert!(timing.min <= timing.median && timing.median <= timing.max);
+    }
+
+    #[test]
+    fn test_bench_part_reports_crashes() {
+        let solution = problems::get_solution(4).unwrap();
+        let input = solution.input_kind().load(&InputParser::new(), Source::text("7,4,9\n\n1 2\n3 x")).unwrap();
+        let result = bench_part(solution.as_ref(), Part::A, &input, 3);
+
+        let panic = result.outcome.clone().unwrap_err();
+        assert!(panic.location.as_deref().unwrap().starts_with("src/problems/problem_04.rs"), "{}", panic);
+        assert!(table(std::slice::from_ref(&result)).lines().nth(1).unwrap().contains("crashed: "));
+        assert!(json(&[result]).starts_with("[{\"day\":4,\"part\":\"a\",\"status\":\"crashed\",\"message\":"));
+    }
+
+    #[test]
+    fn test_json_lists_results() {
+        let result = BenchResult {
+            day: 1,
+            part: Part::B,
+            outcome: Ok(Timing {
+                answer: "5".to_string(),
+                iterations: 2,
+                min: Duration::from_nanos(10),
+                median: Duration::from_nanos(15),
+                max: Duration::from_nanos(20),
+                peak_bytes: 64,
+            }),
+        };
+
+        assert_eq!(
+            json(&[result]),
+            "[{\"day\":1,\"part\":\"b\",\"status\":\"ok\",\"answer\":\"5\",\"iterations\":2,\"min_ns\":10,\"median_ns\":15,\"max_ns\":20,\"peak_bytes\":64}]"
+        );
+    }
+}
@@ -0,0 +1,364 @@
+use std::{fmt, io::{Read, Write}, net::TcpStream, process::{Command, Stdio}, time::Duration};
+
+const TIMEOUT: Duration = Duration::from_secs(30);
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Url {
+    pub secure: bool,
+    pub host: String,
+    pub port: u16,
+    pub path: String,
+}
+
+impl Url {
+
+    pub fn parse(url: &str) -> Result<Self, String> {
+        let (secure, rest) = match url.split_once("://") {
+            Some(("http", rest)) => (false, rest),
+            Some(("https", rest)) => (true, rest),
+            _ => return Err(format!("Invalid URL: {}.", url)),
+        };
+        let (authority, path) = match rest.find('/') {
+            Some(i) => (&rest[..i], &rest[i..]),
+            None => (rest, "/"),
+        };
+        let (host, port) = match authority.rsplit_once(':') {
+            Some((host, port)) => (host, port.parse::<u16>().map_err(|_| format!("Invalid port in {}.", url))?),
+            None => (authority, if secure { 443 } else { 80 }),
+        };
+        if host.is_empty() {
+            return Err(format!("Missing host in {}.", url));
+        }
+        Ok(Self { secure, host: host.to_string(), port, path: path.to_string() })
+    }
+
+    pub fn authority(&self) -> String {
+        let default_port = if self.secure { 443 } else { 80 };
+        match self.port == default_port {
+            true => self.host.clone(),
+            false => format!("{}:{}", self.host, self.port),
+        }
+    }
+
+    pub fn join(&self, path: &str) -> Self {
+        let base = self.path.trim_end_matches('/');
+        Self { secure: self.secure, host: self.host.clone(), port: self.port, path: format!("{}/{}", base, path.trim_start_matches('/')) }
+    }
+}
+
+impl fmt::Display for Url {
+
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let scheme = if self.secure { "https" } else { "http" };
+        write!(f, "{}://{}{}", scheme, self.authority(), self.path)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Response {
+    pub status: u16,
+    pub body: String,
+}
+
+impl Response {
+
+    pub fn is_success(&self) -> bool {
+        (200..300).contains(&self.status)
+    }
+}
+
+pub fn get(url: &Url, headers: &[(&str, &str)]) -> Result<Response, String> {
+    send(url, "GET", headers, None)
+}
+
+pub fn post_form(url: &Url, headers: &[(&str, &str)], fields: &[(&str, &str)]) -> Result<Response, String> {
+    let body = fields.iter().map(|(key, value)| format!("{}={}", encode(key), encode(value))).collect::<Vec<_>>().join("&");
+    let mut headers = headers.to_vec();
+    headers.push(("Content-Type", "application/x-www-form-urlencoded"));
+    send(url, "POST", &headers, Some(&body))
+}
+
+fn encode(s: &str) -> String {
+    s.bytes().map(|b| match b {
+        b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => (b as char).to_string(),
+        _ => format!("%{:02X}", b),
+    }).collect()
+}
+
+fn send(url: &Url, method: &str, headers: &[(&str, &str)], body: Option<&str>) -> Result<Response, String> {
+    if url.secure {
+        send_with_curl(url, method, headers, body)
+    } else {
+        send_plain(url, method, headers, body)
+    }
+}
+
+fn send_plain(url: &Url, method: &str, headers: &[(&str, &str)], body: Option<&str>) -> Result<Response, String> {
+    let address = format!("{}:{}", url.host, url.port);
+    let mut stream = TcpStream::connect(&address).map_err(|error| format!("Unable to connect to {}: {}.", address, error))?;
+    stream.set_read_timeout(Some(TIMEOUT)).map_err(|error| error.to_string())?;
+    stream.set_write_timeout(Some(TIMEOUT)).map_err(|error| error.to_string())?;
+
+    let mut request = format!("{} {} HTTP/1.1\r\nHost: {}\r\nConnection: close\r\n", method, url.path, url.authority());
+    for (name, value) in headers {
+        request.push_str(&format!("{}: {}\r\n", name, value));
+    }
+    if let Some(body) = body {
+        request.push_str(&format!("Content-Length: {}\r\n", body.len()));
+    }
+    request.push_str("\r\n");
+    if let Some(body) = body {
+        request.push_str(body);
+    }
+    stream.write_all(request.as_bytes()).map_err(|error| format!("Unable to send request to {}: {}.", address, error))?;
+
+    let mut raw = vec![];
+    stream.read_to_end(&mut raw).map_err(|error| format!("Unable to read response from {}: {}.", address, error))?;
+    parse_response(&raw)
+}
+
+fn send_with_curl(url: &Url, method: &str, headers: &[(&str, &str)], body: Option<&str>) -> Result<Response, String> {
+    let mut child = Command::new("curl")
+        .args(["--silent", "--show-error", "--config", "-"])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .map_err(|error| format!(
+            "Unable to run curl for {}: {}. https endpoints are reached through the curl command, so install it and put it on PATH.",
+            url, error
+        ))?;
+    child.stdin.take().ok_or("Unable to write to curl.")?
+        .write_all(curl_config(url, method, headers, body).as_bytes())
+        .map_err(|error| format!("Unable to write to curl: {}.", error))?;
+
+    let output = child.wait_with_output().map_err(|error| format!("Unable to read response from {}: {}.", url, error))?;
+    if !output.status.success() {
+        return Err(format!("Request to {} failed: {}", url, String::from_utf8_lossy(&output.stderr).trim()));
+    }
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let (body, status) = stdout.rsplit_once('\n').ok_or(format!("curl returned no status for {}.", url))?;
+    let status = status.trim().parse::<u16>().map_err(|_| format!("curl returned an invalid status for {}: {:?}.", url, status))?;
+    Ok(Response { status, body: body.to_string() })
+}
+
+fn curl_config(url: &Url, method: &str, headers: &[(&str, &str)], body: Option<&str>) -> String {
+    let mut config = vec![
+        ("url", url.to_string()),
+        ("request", method.to_string()),
+        ("max-time", TIMEOUT.as_secs().to_string()),
+        ("write-out", "\n%{http_code}".to_string()),
+    ];
+    config.extend(headers.iter().map(|(name, value)| ("header", format!("{}: {}", name, value))));
+    if let Some(body) = body {
+        config.push(("data-binary", body.to_string()));
+    }
+    config.iter().map(|(option, value)| format!("{} = \"{}\"\n", option, quote(value))).collect()
+}
+
+fn quote(value: &str) -> String {
+    value.chars().map(|c| match c {
+        '\\' => "\\\\".to_string(),
+        '"' => "\\\"".to_string(),
+        '\n' => "\\n".to_string(),
+        '\r' => "\\r".to_string(),
+        '\t' => "\\t".to_string(),
+        c => c.to_string(),
+    }).collect()
+}
+
+fn parse_response(raw: &[u8]) -> Result<Response, String> {
+    let header_end = raw.windows(4).position(|window| window == b"\r\n\r\n").ok_or("Response has no header terminator.")?;
+    let head = String::from_utf8_lossy(&raw[..header_end]);
+    let mut lines = head.split("\r\n");
+    let status_line = lines.next().unwrap_or_default();
+    let status = status_line.split_whitespace().nth(1)
+        .and_then(|code| code.parse::<u16>().ok())
+        .ok_or(format!("Invalid status line: {:?}.", status_line))?;
+
+    let mut chunked = false;
+    let mut content_length = None;
+    for line in lines {
+        if let Some((name, value)) = line.split_once(':') {
+            match name.trim().to_lowercase().as_str() {
+                "transfer-encoding" => chunked = value.trim().eq_ignore_ascii_case("chunked"),
+                "content-length" => content_length = value.trim().parse::<usize>().ok(),
+                _ => {},
+            }
+        }
+    }
+
+    let mut body = raw[header_end + 4..].to_vec();
+    if chunked {
+        body = decode_chunked(&body)?;
+    } else if let Some(length) = content_length {
+        body.truncate(length);
+    }
+    Ok(Response { status, body: String::from_utf8_lossy(&body).to_string() })
+}
+
+fn decode_chunked(mut body: &[u8]) -> Result<Vec<u8>, String> {
+    let mut decoded = vec![];
+    loop {
+        let line_end = body.windows(2).position(|window| window == b"\r\n").ok_or("Truncated chunk header.")?;
+        let size_text = String::from_utf8_lossy(&body[..line_end]);
+        let size = usize::from_str_radix(size_text.split(';').next().unwrap_or_default().trim(), 16)
+            .map_err(|_| format!("Invalid chunk size: {:?}.", size_text))?;
+        body = &body[line_end + 2..];
+        if size == 0 {
+            return Ok(decoded);
+        }
+        if body.len() < size {
+            return Err("Truncated chunk body.".to_string());
+        }
+        decoded.extend_from_slice(&body[..size]);
+        body = body.get(size + 2..).unwrap_or_default();
+    }
+}
+
+#[cfg(test)]
+pub mod test_server {
+    use std::{io::{BufRead, BufReader, Write}, net::TcpListener, sync::mpsc, thread};
+
+    #[derive(Debug, Clone)]
+    pub struct Request {
+        pub method: String,
+        pub path: String,
+        pub headers: Vec<(String, String)>,
+        pub body: String,
+    }
+
+    impl Request {
+
+        pub fn header(&self, name: &str) -> Option<&str> {
+            self.headers.iter().find(|(key, _)| key.eq_ignore_ascii_case(name)).map(|(_, value)| value.as_str())
+        }
+    }
+
+    pub struct TestServer {
+        pub base_url: String,
+        requests: mpsc::Receiver<Request>,
+    }
+
+    impl TestServer {
+
+        pub fn start<F>(expected_requests: usize, respond: F) -> Self
+        where
+            F: Fn(&Request) -> (u16, String) + Send + 'static,
+        {
+            let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+            let base_url = format!("http://{}", listener.local_addr().unwrap());
+            let (sender, requests) = mpsc::channel();
+            thread::spawn(move || {
+                for stream in listener.incoming().take(expected_requests) {
+                    let mut stream = stream.unwrap();
+                    let request = read_request(&mut BufReader::new(&mut stream));
+                    let (status, body) = respond(&request);
+                    sender.send(request).unwrap();
+                    let response = format!("HTTP/1.1 {} Stand-in\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}", status, body.len(), body);
+                    stream.write_all(response.as_bytes()).unwrap();
+                }
+            });
+            Self { base_url, requests }
+        }
+
+        pub fn requests(&self) -> Vec<Request> {
+            self.requests.try_iter().collect()
+        }
+    }
+
+    fn read_request<R: BufRead>(reader: &mut R) -> Request {
+        let mut request_line = String::new();
+        reader.read_line(&mut request_line).unwrap();
+        let mut parts = request_line.split_whitespace();
+        let method = parts.next().unwrap_or_default().to_string();
+        let path = parts.next().unwrap_or_default().to_string();
+
+        let mut headers = vec![];
+        loop {
+            let mut line = String::new();
+            reader.read_line(&mut line).unwrap();
+            let line = line.trim_end();
+            if line.is_empty() {
+                break;
+            }
+            if let Some((name, value)) = line.split_once(':') {
+                headers.push((name.trim().to_string(), value.trim().to_string()));
+            }
+        }
+        let length = headers.iter().find(|(name, _)| name.eq_ignore_ascii_case("content-length"))
+            .and_then(|(_, value)| value.parse::<usize>().ok()).unwrap_or(0);
+        let mut body = vec![0; length];
+        reader.read_exact(&mut body).unwrap();
+        Request { method, path, headers, body: String::from_utf8_lossy(&body).to_string() }
+    }
+}
+
+#[cfg(test)]
+mod test_http {
+    use super::*;
+    use super::test_server::TestServer;
+
+    #[test]
+    fn test_url_parses_host_port_and_path() {
+        assert_eq!(Url::parse("http://127.0.0.1:8080/aoc").unwrap(), Url { secure: false, host: "127.0.0.1".to_string(), port: 8080, path: "/aoc".to_string() });
+        assert_eq!(Url::parse("http://localhost").unwrap().join("/2021/day/1/input").path, "/2021/day/1/input");
+        assert_eq!(Url::parse("https://adventofcode.com").unwrap().join("2021/day/1/input").to_string(), "https://adventofcode.com/2021/day/1/input");
+        assert!(Url::parse("adventofcode.com").is_err());
+
+        assert_eq!(Url::parse("http://localhost:8080/aoc").unwrap().authority(), "localhost:8080");
+        assert_eq!(Url::parse("http://localhost:80").unwrap().authority(), "localhost");
+        assert_eq!(Url::parse("https://adventofcode.com:443").unwrap().authority(), "adventofcode.com");
+        assert_eq!(Url::parse("https://adventofcode.com:8443").unwrap().authority(), "adventofcode.com:8443");
+    }
+
+    #[test]
+    fn test_get_and_post_round_trip() {
+        let server = TestServer::start(2, |request| (200, format!("{} {} {}", request.method, request.path, request.body)));
+        let url = Url::parse(&server.base_url).unwrap();
+
+        let response = get(&url.join("a"), &[("Cookie", "session=abc")]).unwrap();
+        assert_eq!(response, Response { status: 200, body: "GET /a ".to_string() });
+
+        let response = post_form(&url.join("b"), &[], &[("level", "1"), ("answer", "a b")]).unwrap();
+        assert_eq!(response.body, "POST /b level=1&answer=a%20b");
+
+        let requests = server.requests();
+        assert_eq!(requests[0].header("cookie"), Some("session=abc"));
+        assert_eq!(requests[0].header("host"), Some(url.authority().as_str()));
+    }
+
+    fn curl_available() -> bool {
+        Command::new("curl").arg("--version").output().is_ok_and(|output| output.status.success())
+    }
+
+    #[test]
+    fn test_curl_round_trip() {
+        if !curl_available() {
+            eprintln!("Skipping test_curl_round_trip: `curl --version` failed, so the https transport cannot be exercised.");
+            return;
+        }
+        let server = TestServer::start(1, |request| (201, format!("{} {} {}", request.method, request.path, request.body)));
+        let url = Url::parse(&server.base_url).unwrap().join("answer");
+
+        let response = send_with_curl(&url, "POST", &[("Cookie", "session=abc")], Some("level=1&answer=a%20b")).unwrap();
+        assert_eq!(response, Response { status: 201, body: "POST /answer level=1&answer=a%20b".to_string() });
+        assert_eq!(server.requests()[0].header("cookie"), Some("session=abc"));
+    }
+
+    #[test]
+    fn test_curl_config_quotes_values() {
+        let url = Url::parse("https://adventofcode.com").unwrap();
+        let config = curl_config(&url, "GET", &[("User-Agent", "say \"hi\"\\")], None);
+
+        assert!(config.starts_with("url = \"https://adventofcode.com/\"\nrequest = \"GET\"\n"), "{}", config);
+        assert!(config.contains("header = \"User-Agent: say \\\"hi\\\"\\\\\"\n"), "{}", config);
+        assert!(!config.contains("data-binary"));
+    }
+
+    #[test]
+    fn test_response_decodes_chunked_body() {
+        let raw = b"HTTP/1.1 200 OK\r\nTransfer-Encoding: chunked\r\n\r\n5\r\nhello\r\n6\r\n world\r\n0\r\n\r\n";
+        assert_eq!(parse_response(raw).unwrap().body, "hello world");
+    }
+}
@@ -0,0 +1,103 @@
+use std::{error::Error, fmt, io, path::PathBuf};
+
+#[derive(Debug)]
+pub enum InputError {
+    NotFound {
+        filepath: String,
+        tried: Vec<PathBuf>,
+    },
+    Io {
+        path: PathBuf,
+        source: io::Error,
+    },
+    Parse {
+        file: String,
+        line: usize,
+        column: usize,
+        text: String,
+        reason: String,
+    },
+    Shape {
+        expected: String,
+        found: String,
+    },
+    Multiple(Vec<InputError>),
+}
+
+impl fmt::Display for InputError {
+
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NotFound { filepath, tried } => write!(
+                f,
+                "Unable to find input file {}. Tried: {}.",
+                filepath,
+                tried.iter().map(|path| path.display().to_string()).collect::<Vec<_>>().join(", ")
+            ),
+            Self::Io { path, source } => write!(f, "Unable to read {}: {}.", path.display(), source),
+            Self::Parse { file, line, column, text, reason } => write!(
+                f, "{}:{}:{}: {} in {:?}.", file, line, column, reason, text
+            ),
+            Self::Shape { expected, found } => write!(f, "Expected {} but found {}.", expected, found),
+            Self::Multiple(errors) => {
+                write!(f, "{} errors:", errors.len())?;
+                for error in errors {
+                    write!(f, "\n  {}", error)?;
+                }
+                Ok(())
+            },
+        }
+    }
+}
+
+impl Error for InputError {
+
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Io { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test_error {
+    use super::*;
+
+    #[test]
+    fn test_parse_error_reports_position() {
+        let error = InputError::Parse {
+            file: "input_01.txt".to_string(),
+            line: 3,
+            column: 2,
+            text: "1x2".to_string(),
+            reason: "Invalid digit".to_string(),
+        };
+
+        assert_eq!(error.to_string(), "input_01.txt:3:2: Invalid digit in \"1x2\".");
+    }
+
+    #[test]
+    fn test_io_error_exposes_source() {
+        let error = InputError::Io {
+            path: PathBuf::from("/tmp/missing.txt"),
+            source: io::Error::new(io::ErrorKind::PermissionDenied, "denied"),
+        };
+
+        assert!(error.to_string().contains("/tmp/missing.txt"));
+        assert!(error.source().is_some());
+    }
+
+    #[test]
+    fn test_multiple_errors_are_listed_one_per_line() {
+        let error = InputError::Multiple(vec![
+            InputError::Shape { expected: "5 columns".to_string(), found: "4 columns".to_string() },
+            InputError::Shape { expected: "5 rows".to_string(), found: "6 rows".to_string() },
+        ]);
+
+        assert_eq!(
+            error.to_string(),
+            "2 errors:\n  Expected 5 columns but found 4 columns.\n  Expected 5 rows but found 6 rows."
+        );
+    }
+}
@@ -0,0 +1,111 @@
+use std::{fs, path::{Path, PathBuf}};
+
+use crate::http::{self, Url};
+
+pub const SESSION_VARIABLE: &str = "AOC_SESSION";
+pub const BASE_URL_VARIABLE: &str = "AOC_BASE_URL";
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+pub const YEAR: u32 = 2021;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FetchOutcome {
+    Cached(PathBuf),
+    Downloaded(PathBuf),
+}
+
+pub struct InputFetcher {
+    base_url: Url,
+    session: String,
+    year: u32,
+    cache_dir: PathBuf,
+}
+
+impl InputFetcher {
+
+    pub fn new<P: AsRef<Path>>(base_url: &str, session: &str, cache_dir: P) -> Result<Self, String> {
+        Ok(Self {
+            base_url: Url::parse(base_url)?,
+            session: session.trim().to_string(),
+            year: YEAR,
+            cache_dir: cache_dir.as_ref().to_path_buf(),
+        })
+    }
+
+    pub fn with_year(mut self, year: u32) -> Self {
+        self.year = year;
+        self
+    }
+
+    pub fn input_url(&self, day: u32) -> Url {
+        self.base_url.join(&format!("{}/day/{}/input", self.year, day))
+    }
+
+    pub fn cache_path(&self, day: u32) -> PathBuf {
+        self.cache_dir.join(format!("input_{:02}.txt", day))
+    }
+
+    pub fn fetch(&self, day: u32) -> Result<FetchOutcome, String> {
+        let path = self.cache_path(day);
+        if path.is_file() {
+            return Ok(FetchOutcome::Cached(path));
+        }
+
+        let cookie = format!("session={}", self.session);
+        let response = http::get(&self.input_url(day), &[("Cookie", &cookie), ("User-Agent", "advent-of-code-2021 input fetcher")])?;
+        if !response.is_success() {
+            return Err(format!(
+                "Fetching day {} returned status {}: {}",
+                day, response.status, response.body.lines().next().unwrap_or_default()
+            ));
+        }
+
+        fs::create_dir_all(&self.cache_dir).map_err(|error| format!("Unable to create {}: {}.", self.cache_dir.display(), error))?;
+        let partial_path = path.with_extension("txt.partial");
+        fs::write(&partial_path, &response.body).map_err(|error| format!("Unable to write {}: {}.", partial_path.display(), error))?;
+        fs::rename(&partial_path, &path).map_err(|error| format!("Unable to move input into {}: {}.", path.display(), error))?;
+        Ok(FetchOutcome::Downloaded(path))
+    }
+}
+
+#[cfg(test)]
+mod test_fetch {
+    use super::*;
+    use crate::http::test_server::TestServer;
+    use crate::input::input::InputParser;
+    use crate::scratch::ScratchDir;
+
+    #[test]
+    fn test_fetch_downloads_into_parser_layout() {
+        let server = TestServer::start(1, |request| match request.header("cookie") {
+            Some("session=secret") => (200, "199\n200\n208\n".to_string()),
+            _ => (400, "Puzzle inputs differ by user.".to_string()),
+        });
+        let dir = ScratchDir::new("fetch");
+        let fetcher = InputFetcher::new(&server.base_url, "secret\n", &dir).unwrap();
+
+        assert_eq!(fetcher.fetch(1).unwrap(), FetchOutcome::Downloaded(dir.join("input_01.txt")));
+        assert_eq!(server.requests()[0].path, "/2021/day/1/input");
+        assert_eq!(InputParser::with_input_dir(&dir).parse_as_i32("input_01.txt").unwrap(), vec![199, 200, 208]);
+    }
+
+    #[test]
+    fn test_fetch_skips_cached_files() {
+        let dir = ScratchDir::new("fetch");
+        fs::write(dir.join("input_02.txt"), "forward 5\n").unwrap();
+        let fetcher = InputFetcher::new("http://127.0.0.1:9", "secret", &dir).unwrap();
+
+        assert_eq!(fetcher.fetch(2).unwrap(), FetchOutcome::Cached(dir.join("input_02.txt")));
+    }
+
+    #[test]
+    fn test_fetch_does_not_cache_failures() {
+        let server = TestServer::start(1, |_| (404, "Not found".to_string()));
+        let dir = ScratchDir::new("fetch");
+        let fetcher = InputFetcher::new(&server.base_url, "secret", &dir).unwrap().with_year(2020);
+
+        let error = fetcher.fetch(3).unwrap_err();
+        assert!(error.contains("404"), "{}", error);
+        assert_eq!(server.requests()[0].path, "/2020/day/3/input");
+        assert!(!fetcher.cache_path(3).exists());
+    }
+}
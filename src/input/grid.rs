@@ -0,0 +1,262 @@
+use std::fmt;
+
+use crate::input::error::InputError;
+
+const NEIGHBOUR_OFFSETS_4: [(isize, isize); 4] = [(0, -1), (-1, 0), (1, 0), (0, 1)];
+const NEIGHBOUR_OFFSETS_8: [(isize, isize); 8] = [
+    (-1, -1), (0, -1), (1, -1),
+    (-1, 0), (1, 0),
+    (-1, 1), (0, 1), (1, 1),
+];
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+
+    pub fn new(width: usize, height: usize, cells: Vec<T>) -> Result<Self, InputError> {
+        if cells.len() != width * height {
+            return Err(InputError::Shape {
+                expected: format!("{} cells for a {}x{} grid", width * height, width, height),
+                found: format!("{} cells", cells.len()),
+            });
+        }
+        Ok(Self { width, height, cells })
+    }
+
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Result<Self, InputError> {
+        let height = rows.len();
+        let width = rows.first().map(|row| row.len()).unwrap_or(0);
+        if let Some((y, row)) = rows.iter().enumerate().find(|(_, row)| row.len() != width) {
+            return Err(InputError::Shape {
+                expected: format!("every row to have width {}", width),
+                found: format!("width {} on row {}", row.len(), y + 1),
+            });
+        }
+        Self::new(width, height, rows.into_iter().flatten().collect())
+    }
+
+    pub fn parse_with<F>(origin: &str, lines: &[String], mut f: F) -> Result<Self, InputError>
+    where
+        F: FnMut(char) -> Option<T>,
+    {
+        let start = lines.iter().position(|line| !line.trim().is_empty()).unwrap_or(0);
+        let end = lines.iter().rposition(|line| !line.trim().is_empty()).map(|i| i + 1).unwrap_or(0);
+        let width = lines.get(start).map(|line| line.trim_end().chars().count()).unwrap_or(0);
+        let height = end.saturating_sub(start);
+        let mut cells = Vec::with_capacity(width * height);
+        for (i, line) in lines.iter().enumerate().take(end).skip(start) {
+            let line = line.trim_end();
+            let line_error = |column: usize, reason: String| InputError::Parse {
+                file: origin.to_string(),
+                line: i + 1,
+                column,
+                text: line.to_string(),
+                reason,
+            };
+            let row_width = line.chars().count();
+            if row_width != width {
+                return Err(line_error(row_width.min(width) + 1, format!("Expected width {} but found {}", width, row_width)));
+            }
+            for (j, c) in line.chars().enumerate() {
+                match f(c) {
+                    Some(cell) => cells.push(cell),
+                    None => return Err(line_error(j + 1, format!("Unexpected grid character {:?}", c))),
+                }
+            }
+        }
+        Self::new(width, height, cells)
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    pub fn contains(&self, x: usize, y: usize) -> bool {
+        x < self.width && y < self.height
+    }
+
+    pub fn get(&self, x: usize, y: usize) -> Option<&T> {
+        if self.contains(x, y) {
+            self.cells.get(y * self.width + x)
+        } else {
+            None
+        }
+    }
+
+    pub fn get_mut(&mut self, x: usize, y: usize) -> Option<&mut T> {
+        if self.contains(x, y) {
+            self.cells.get_mut(y * self.width + x)
+        } else {
+            None
+        }
+    }
+
+    pub fn set(&mut self, x: usize, y: usize, value: T) {
+        let cell = self.get_mut(x, y).expect("Position should be inside the grid.");
+        *cell = value;
+    }
+
+    pub fn positions(&self) -> impl Iterator<Item = (usize, usize)> + '_ {
+        (0..self.height).flat_map(move |y| (0..self.width).map(move |x| (x, y)))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = ((usize, usize), &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        (0..self.height).map(move |y| self.row(y))
+    }
+
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        assert!(x < self.width, "Column {} is outside a grid of width {}.", x, self.width);
+        self.cells.iter().skip(x).step_by(self.width)
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(move |x| self.column(x))
+    }
+
+    pub fn neighbours4(&self, x: usize, y: usize) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.offset_positions(x, y, &NEIGHBOUR_OFFSETS_4)
+    }
+
+    pub fn neighbours8(&self, x: usize, y: usize) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.offset_positions(x, y, &NEIGHBOUR_OFFSETS_8)
+    }
+
+    fn offset_positions<'a>(&'a self, x: usize, y: usize, offsets: &'a [(isize, isize)]) -> impl Iterator<Item = (usize, usize)> + 'a {
+        offsets.iter().filter_map(move |(dx, dy)| {
+            let nx = x.checked_add_signed(*dx)?;
+            let ny = y.checked_add_signed(*dy)?;
+            if self.contains(nx, ny) { Some((nx, ny)) } else { None }
+        })
+    }
+
+    pub fn map<U, F: FnMut(&T) -> U>(&self, f: F) -> Grid<U> {
+        Grid { width: self.width, height: self.height, cells: self.cells.iter().map(f).collect() }
+    }
+}
+
+impl Grid<u8> {
+
+    pub fn parse_digits(origin: &str, lines: &[String]) -> Result<Self, InputError> {
+        Self::parse_with(origin, lines, |c| c.to_digit(10).map(|digit| digit as u8))
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (y, row) in self.rows().enumerate() {
+            if y > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test_grid {
+    use super::*;
+
+    fn lines(text: &str) -> Vec<String> {
+        text.lines().map(|line| line.to_string()).collect()
+    }
+
+    #[test]
+    fn test_grid_parses_digits_and_prints_back() {
+        let text = "2199943210\n3987894921\n9856789892";
+        let grid = Grid::parse_digits("example", &lines(text)).unwrap();
+
+        assert_eq!(grid.width(), 10);
+        assert_eq!(grid.height(), 3);
+        assert_eq!(grid.get(1, 0), Some(&1));
+        assert_eq!(grid.get(10, 0), None);
+        assert_eq!(grid.to_string(), text);
+    }
+
+    #[test]
+    fn test_grid_parses_with_char_mapping() {
+        let grid = Grid::parse_with("example", &lines("#.\n.#\n\n"), |c| match c {
+            '#' => Some(true),
+            '.' => Some(false),
+            _ => None,
+        }).unwrap();
+
+        assert_eq!(grid.height(), 2);
+        assert_eq!(grid.map(|lit| if *lit { '#' } else { '.' }).to_string(), "#.\n.#");
+
+        let error = Grid::parse_with("example", &lines("#.\n.x"), |c| if c == '#' || c == '.' { Some(c) } else { None }).unwrap_err();
+        assert!(matches!(error, InputError::Parse { line: 2, column: 2, .. }));
+    }
+
+    #[test]
+    fn test_grid_skips_leading_blank_lines() {
+        let grid = Grid::parse_digits("example", &lines("\n  \n12\n34\n\n")).unwrap();
+
+        assert_eq!((grid.width(), grid.height()), (2, 2));
+        assert_eq!(grid.to_string(), "12\n34");
+
+        let error = Grid::parse_digits("example", &lines("\n12\n3")).unwrap_err();
+        assert!(matches!(error, InputError::Parse { line: 3, .. }));
+        assert_eq!(Grid::parse_digits("example", &lines("\n\n")).unwrap().height(), 0);
+    }
+
+    #[test]
+    fn test_grid_rejects_ragged_rows() {
+        let error = Grid::parse_digits("example", &lines("123\n45\n678")).unwrap_err();
+        assert!(matches!(error, InputError::Parse { line: 2, .. }));
+
+        let error = Grid::from_rows(vec![vec![1, 2], vec![3]]).unwrap_err();
+        assert!(matches!(error, InputError::Shape { .. }));
+    }
+
+    #[test]
+    fn test_grid_neighbours() {
+        let grid = Grid::parse_digits("example", &lines("123\n456\n789")).unwrap();
+
+        assert_eq!(grid.neighbours4(0, 0).collect::<Vec<_>>(), vec![(1, 0), (0, 1)]);
+        assert_eq!(grid.neighbours4(1, 1).count(), 4);
+        assert_eq!(grid.neighbours8(1, 1).count(), 8);
+        assert_eq!(grid.neighbours8(2, 2).collect::<Vec<_>>(), vec![(1, 1), (2, 1), (1, 2)]);
+    }
+
+    #[test]
+    fn test_grid_rows_and_columns() {
+        let grid = Grid::parse_digits("example", &lines("123\n456")).unwrap();
+
+        assert_eq!(grid.rows().map(|row| row.iter().map(|d| *d as u32).sum::<u32>()).collect::<Vec<_>>(), vec![6, 15]);
+        assert_eq!(grid.columns().map(|column| column.copied().collect::<Vec<_>>()).collect::<Vec<_>>(), vec![
+            vec![1, 4],
+            vec![2, 5],
+            vec![3, 6],
+        ]);
+        assert_eq!(grid.iter().filter(|(_, digit)| **digit % 2 == 0).map(|(position, _)| position).collect::<Vec<_>>(), vec![(1, 0), (0, 1), (2, 1)]);
+    }
+}
@@ -1,59 +1,288 @@
-use std::{fs::File, io::Read, path::Path};
+use std::{env, fmt::Display, fs, path::{Path, PathBuf}, str::FromStr};
 
-const INPUT_PATH_HEAD: &str = "C:/Users/cmsdu/repos/advent-of-code-2021/input/";
+use crate::input::{error::InputError, grid::Grid, source::Source};
 
-pub struct InputParser;
+pub const INPUT_DIR_VARIABLE: &str = "AOC_INPUT_DIR";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BinaryInput {
+    pub width: usize,
+    pub values: Vec<u64>,
+}
+
+impl BinaryInput {
+
+    pub fn new(width: usize, values: Vec<u64>) -> Self {
+        Self { width, values }
+    }
+
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+}
+
+pub struct InputParser {
+    input_dir: Option<PathBuf>,
+}
 
 impl InputParser {
 
     pub fn new() -> InputParser {
-        InputParser {}
+        InputParser { input_dir: None }
+    }
+
+    pub fn with_input_dir<P: AsRef<Path>>(input_dir: P) -> InputParser {
+        InputParser { input_dir: Some(input_dir.as_ref().to_path_buf()) }
+    }
+
+    pub fn default_input_dir() -> PathBuf {
+        Path::new(env!("CARGO_MANIFEST_DIR")).join("input")
+    }
+
+    pub fn candidate_dirs(&self) -> Vec<PathBuf> {
+        Self::candidate_dirs_from(self.input_dir.as_deref(), env::var_os(INPUT_DIR_VARIABLE).map(PathBuf::from).as_deref())
+    }
+
+    pub fn cache_dir(&self) -> PathBuf {
+        self.candidate_dirs().remove(0)
+    }
+
+    fn candidate_dirs_from(flag_dir: Option<&Path>, variable_dir: Option<&Path>) -> Vec<PathBuf> {
+        let mut dirs = vec![];
+        if let Some(dir) = flag_dir {
+            dirs.push(dir.to_path_buf());
+        }
+        if let Some(dir) = variable_dir {
+            dirs.push(dir.to_path_buf());
+        }
+        dirs.push(Self::default_input_dir());
+        dirs
+    }
+
+    pub fn resolve(&self, filepath: &str) -> Result<PathBuf, InputError> {
+        let path = Path::new(filepath);
+        let tried = if path.is_absolute() {
+            vec![path.to_path_buf()]
+        } else {
+            self.candidate_dirs().into_iter().map(|dir| dir.join(path)).collect()
+        };
+        match tried.iter().find(|candidate| candidate.is_file()) {
+            Some(found) => Ok(found.clone()),
+            None => Err(InputError::NotFound { filepath: filepath.to_string(), tried }),
+        }
+    }
+
+    pub fn parse_as_i32<'a, S: Into<Source<'a>>>(&self, source: S) -> Result<Vec<i32>, InputError> {
+        let (origin, lines) = self.read_lines(source.into())?;
+        Self::lines_as_i32(&origin, &lines)
     }
 
-    pub fn parse_as_i32(&self, filepath: &str) -> Result<Vec<i32>, String> {
-        self.parse_as_string(filepath)?.iter().enumerate().map(
-            |(i, line)| line.parse::<i32>()
-                .map_err(|_| format!("Unable to parse line {} to i32.", i)
-            )
+    pub fn parse_as_binary<'a, S: Into<Source<'a>>>(&self, source: S) -> Result<BinaryInput, InputError> {
+        let (origin, lines) = self.read_lines(source.into())?;
+        Self::lines_as_binary(&origin, &lines)
+    }
+
+    pub fn parse_as_string<'a, S: Into<Source<'a>>>(&self, source: S) -> Result<Vec<String>, InputError> {
+        self.read_lines(source.into()).map(|(_origin, lines)| lines)
+    }
+
+    pub fn parse_lines<'a, T, S>(&self, source: S) -> Result<Vec<T>, InputError>
+    where
+        T: FromStr,
+        T::Err: Display,
+        S: Into<Source<'a>>,
+    {
+        let (origin, lines) = self.read_lines(source.into())?;
+        Self::lines_as(&origin, &lines)
+    }
+
+    pub fn parse_with<'a, T, S, F>(&self, source: S, parse: F) -> Result<T, InputError>
+    where
+        S: Into<Source<'a>>,
+        F: FnOnce(&str, &[String]) -> Result<T, InputError>,
+    {
+        let (origin, lines) = self.read_lines(source.into())?;
+        parse(&origin, &lines)
+    }
+
+    pub fn parse_comma_separated<'a, T, S>(&self, source: S) -> Result<Vec<T>, InputError>
+    where
+        T: FromStr,
+        T::Err: Display,
+        S: Into<Source<'a>>,
+    {
+        let (origin, lines) = self.read_lines(source.into())?;
+        Self::lines_as_comma_separated(&origin, &lines)
+    }
+
+    pub fn lines_as_comma_separated<T>(origin: &str, lines: &[String]) -> Result<Vec<T>, InputError>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        let mut values = vec![];
+        let mut errors = vec![];
+        for (i, line) in lines.iter().enumerate() {
+            if line.trim().is_empty() {
+                continue;
+            }
+            let mut offset = 0;
+            for token in line.split(',') {
+                let leading_whitespace = token.len() - token.trim_start().len();
+                match token.trim().parse::<T>() {
+                    Ok(value) => values.push(value),
+                    Err(error) => errors.push(InputError::Parse {
+                        file: origin.to_string(),
+                        line: i + 1,
+                        column: line[..offset + leading_whitespace].chars().count() + 1,
+                        text: token.trim().to_string(),
+                        reason: error.to_string(),
+                    }),
+                }
+                offset += token.len() + 1;
+            }
+        }
+        match errors.len() {
+            0 => Ok(values),
+            1 => Err(errors.remove(0)),
+            _ => Err(InputError::Multiple(errors)),
+        }
+    }
+
+    pub fn parse_as_grid<'a, S: Into<Source<'a>>>(&self, source: S) -> Result<Grid<u8>, InputError> {
+        let (origin, lines) = self.read_lines(source.into())?;
+        Grid::parse_digits(&origin, &lines)
+    }
+
+    pub fn parse_as_grid_with<'a, T, S, F>(&self, source: S, f: F) -> Result<Grid<T>, InputError>
+    where
+        S: Into<Source<'a>>,
+        F: FnMut(char) -> Option<T>,
+    {
+        let (origin, lines) = self.read_lines(source.into())?;
+        Grid::parse_with(&origin, &lines, f)
+    }
+
+    pub fn parse_blocks<'a, S: Into<Source<'a>>>(&self, source: S) -> Result<Vec<Vec<String>>, InputError> {
+        self.parse_as_string(source).map(Self::blocks)
+    }
+
+    pub fn blocks(lines: Vec<String>) -> Vec<Vec<String>> {
+        let mut result = vec![];
+        let mut block = vec![];
+        for line in lines {
+            let line = line.trim_end_matches('\r');
+            if line.trim().is_empty() {
+                if !block.is_empty() {
+                    result.push(block);
+                    block = vec![];
+                }
+            } else {
+                block.push(line.to_string());
+            }
+        }
+        if !block.is_empty() {
+            result.push(block);
+        }
+        result
+    }
+
+    fn read_lines(&self, source: Source) -> Result<(String, Vec<String>), InputError> {
+        let (origin, contents) = match source {
+            Source::File(filepath) => {
+                let full_path = self.resolve(filepath)?;
+                let contents = fs::read_to_string(&full_path).map_err(|source| InputError::Io { path: full_path.clone(), source })?;
+                (full_path.display().to_string(), contents)
+            },
+            other => {
+                let origin = other.describe();
+                let contents = other.read_to_string().map_err(|source| InputError::Io { path: PathBuf::from(&origin), source })?;
+                (origin, contents)
+            },
+        };
+        Ok((origin, contents.lines().map(|line| line.to_string()).collect()))
+    }
+
+    pub fn lines_as<T>(origin: &str, lines: &[String]) -> Result<Vec<T>, InputError>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        let mut values = vec![];
+        let mut errors = vec![];
+        for (i, line) in lines.iter().enumerate() {
+            match line.parse::<T>() {
+                Ok(value) => values.push(value),
+                Err(error) => errors.push(InputError::Parse {
+                    file: origin.to_string(),
+                    line: i + 1,
+                    column: 1,
+                    text: line.to_string(),
+                    reason: error.to_string(),
+                }),
+            }
+        }
+        match errors.len() {
+            0 => Ok(values),
+            1 => Err(errors.remove(0)),
+            _ => Err(InputError::Multiple(errors)),
+        }
+    }
+
+    fn lines_as_i32(origin: &str, lines: &[String]) -> Result<Vec<i32>, InputError> {
+        lines.iter().enumerate().map(
+            |(i, line)| line.trim().parse::<i32>().map_err(|error| InputError::Parse {
+                file: origin.to_string(),
+                line: i + 1,
+                column: 1,
+                text: line.to_string(),
+                reason: format!("Unable to parse i32 ({})", error),
+            })
         ).collect()
     }
 
-    pub fn parse_as_binary(&self, filepath: &str) -> Result<Vec<u32>, String> {
-        self.parse_as_string(filepath)?.into_iter().map(
-            |line| line.chars().map(
-                |c| match c {
+    fn lines_as_binary(origin: &str, lines: &[String]) -> Result<BinaryInput, InputError> {
+        let width = lines.first().map(|line| line.trim().len()).unwrap_or(0);
+        let mut values = vec![];
+        for (i, line) in lines.iter().enumerate() {
+            let line_error = |column: usize, reason: String| InputError::Parse {
+                file: origin.to_string(),
+                line: i + 1,
+                column,
+                text: line.to_string(),
+                reason,
+            };
+            let digits = line.trim();
+            if digits.len() != width {
+                return Err(line_error(1, format!("Expected {} binary digits but found {}", width, digits.len())));
+            }
+            if width > 64 {
+                return Err(line_error(65, format!("Binary values are limited to 64 bits but found {}", width)));
+            }
+            let mut result: u64 = 0;
+            for (j, c) in digits.chars().enumerate() {
+                let bit = match c {
                     '0' => 0,
                     '1' => 1,
-                    _ => 2,
-                }
-            ).collect::<Vec<_>>()
-        ).map(|bytes| if bytes.iter().all(|b| *b == 0 || *b == 1) { Ok(bytes) } else { Err(format!("Invalid byte in {:?}", bytes))}).collect::<Vec<_>>()
-            .into_iter().map(|maybe_bytes| {
-                maybe_bytes.map(
-                    |bytes| {
-                        let mut result: u32 = 0;
-                        for byte in bytes {
-                            result = result << 1;
-                            result += byte as u32;
-                        }
-                        Ok(result)
-                    }
-                )?
-            }).collect()
-    }
-
-    pub fn parse_as_string(&self, filepath: &str) -> Result<Vec<String>, String> {
-        let full_path_string = format!("{}{}", INPUT_PATH_HEAD, filepath);
-        let full_path = Path::new(&full_path_string);
-        let mut file = File::open(full_path).map_err(|_| "Unable to open file.".to_string())?;
-        let mut contents = String::new();
-        file.read_to_string(&mut contents).map_err(|_| "Unable to read file.".to_string())?;
-        Ok(contents.lines().map(|line| line.to_string()).collect())
-    }
-
-    pub fn chunk<T>(input: Vec<T>, chunk_size: usize) -> Result<Vec<Vec<T>>, String> {
-        if input.len() % chunk_size != 0 {
-            return Err(format!("Input length {} is not divisible by chunk size {}.", input.len(), chunk_size));
+                    _ => return Err(line_error(j + 1, format!("Invalid binary digit {:?}", c))),
+                };
+                result = (result << 1) | bit;
+            }
+            values.push(result);
+        }
+        Ok(BinaryInput::new(width, values))
+    }
+
+    pub fn chunk<T>(input: Vec<T>, chunk_size: usize) -> Result<Vec<Vec<T>>, InputError> {
+        if chunk_size == 0 || !input.len().is_multiple_of(chunk_size) {
+            return Err(InputError::Shape {
+                expected: format!("a multiple of {} items", chunk_size),
+                found: format!("{} items", input.len()),
+            });
         }
         let mut result = vec![];
         let mut chunk = vec![];
@@ -78,7 +307,7 @@ mod test_input {
     fn test_input_parses_input() {
         
         let parser = InputParser::new();
-        let parsed = parser.parse_as_i32("input_001.txt").unwrap();
+        let parsed = parser.parse_as_i32("input_01.txt").unwrap();
 
         assert_eq!(parsed.len(), 2000);
     }
@@ -86,13 +315,197 @@ mod test_input {
     #[test]
     fn test_input_parses_binary() {
                 
-        let input = InputParser::new().parse_as_binary("input_003.txt").unwrap();
-        let shortened_input = input.iter().take(3).map(|s| *s).collect::<Vec<u32>>();
+        let input = InputParser::new().parse_as_binary("input_03.txt").unwrap();
+        let shortened_input = input.values.iter().take(3).map(|s| *s).collect::<Vec<u64>>();
 
         // 111100101100
         // 101100110001
         // 100110100101
 
+        assert_eq!(input.width, 12);
+        assert_eq!(shortened_input, vec![0b111100101100, 0b101100110001, 0b100110100101]);
+
+    }
+
+    #[test]
+    fn test_input_resolves_from_default_input_dir() {
+        let resolved = InputParser::new().resolve("input_01.txt").unwrap();
+
+        assert_eq!(resolved, InputParser::default_input_dir().join("input_01.txt"));
+    }
+
+    #[test]
+    fn test_input_prefers_flag_dir_over_variable_dir() {
+        let flag_dir = Path::new("/flag");
+        let variable_dir = Path::new("/variable");
+
+        assert_eq!(
+            InputParser::candidate_dirs_from(Some(flag_dir), Some(variable_dir)),
+            vec![flag_dir.to_path_buf(), variable_dir.to_path_buf(), InputParser::default_input_dir()]
+        );
+        assert_eq!(
+            InputParser::candidate_dirs_from(None, None),
+            vec![InputParser::default_input_dir()]
+        );
+    }
+
+    #[test]
+    fn test_input_falls_back_when_flag_dir_is_missing_the_file() {
+        let parser = InputParser::with_input_dir("/nonexistent-input-dir");
+        let parsed = parser.parse_as_i32("input_01.txt").unwrap();
+
+        assert_eq!(parsed.len(), 2000);
+    }
+
+    #[test]
+    fn test_input_error_lists_tried_paths() {
+        let parser = InputParser::with_input_dir("/nonexistent-input-dir");
+        let error = parser.resolve("input_99.txt").unwrap_err();
+
+        match error {
+            InputError::NotFound { filepath, tried } => {
+                assert_eq!(filepath, "input_99.txt");
+                assert_eq!(tried[0], Path::new("/nonexistent-input-dir/input_99.txt"));
+                assert_eq!(tried.last().unwrap(), &InputParser::default_input_dir().join("input_99.txt"));
+            },
+            other => panic!("Expected NotFound but got {:?}.", other),
+        }
+    }
+
+    #[test]
+    fn test_input_reports_parse_error_position() {
+        let lines = vec!["1011".to_string(), "1021".to_string()];
+        let error = InputParser::lines_as_binary("example.txt", &lines).unwrap_err();
+
+        match error {
+            InputError::Parse { file, line, column, text, .. } => {
+                assert_eq!(file, "example.txt");
+                assert_eq!(line, 2);
+                assert_eq!(column, 3);
+                assert_eq!(text, "1021");
+            },
+            other => panic!("Expected Parse but got {:?}.", other),
+        }
+    }
+
+    #[test]
+    fn test_input_rejects_inconsistent_or_oversized_binary() {
+        let parser = InputParser::new();
+
+        let error = parser.parse_as_binary(Source::text("00100\n1111")).unwrap_err();
+        assert!(matches!(error, InputError::Parse { line: 2, .. }));
 
+        let wide = "1".repeat(65);
+        let error = parser.parse_as_binary(Source::text(&wide)).unwrap_err();
+        assert!(matches!(error, InputError::Parse { line: 1, column: 65, .. }));
+
+        let widest = "1".repeat(64);
+        assert_eq!(parser.parse_as_binary(Source::text(&widest)).unwrap().values, vec![u64::MAX]);
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_input_parses_text() {
+        let parser = InputParser::new();
+
+        assert_eq!(parser.parse_as_i32(Source::text("199\n200\n208\n")).unwrap(), vec![199, 200, 208]);
+        assert_eq!(parser.parse_as_binary(Source::text("00100\n11110")).unwrap(), BinaryInput::new(5, vec![0b00100, 0b11110]));
+        assert_eq!(parser.parse_as_string(Source::text("forward 5\r\ndown 5\r\n")).unwrap(), vec!["forward 5", "down 5"]);
+    }
+
+    #[test]
+    fn test_input_parses_reader() {
+        let parser = InputParser::new();
+        let reader = std::io::Cursor::new(b"3\n-4\n".to_vec());
+
+        assert_eq!(parser.parse_as_i32(Source::reader(reader)).unwrap(), vec![3, -4]);
+
+        let error = parser.parse_as_i32(Source::reader("1\nx".as_bytes())).unwrap_err();
+        assert!(matches!(error, InputError::Parse { ref file, line: 2, .. } if file == "<reader>"));
+    }
+
+    #[test]
+    fn test_input_parses_lines_with_from_str() {
+        let parser = InputParser::new();
+
+        assert_eq!(parser.parse_lines::<u64, _>(Source::text("1\n26984457539")).unwrap(), vec![1, 26984457539]);
+        assert_eq!(parser.parse_lines::<i32, _>("input_01.txt").unwrap().len(), 2000);
+    }
+
+    #[test]
+    fn test_input_collects_every_line_error() {
+        let error = InputParser::new().parse_lines::<u8, _>(Source::text("1\nx\n2\n300")).unwrap_err();
+
+        match error {
+            InputError::Multiple(errors) => {
+                let lines: Vec<usize> = errors.iter().map(|error| match error {
+                    InputError::Parse { line, .. } => *line,
+                    other => panic!("Expected Parse but got {:?}.", other),
+                }).collect();
+                assert_eq!(lines, vec![2, 4]);
+            },
+            other => panic!("Expected Multiple but got {:?}.", other),
+        }
+    }
+
+    #[test]
+    fn test_input_parses_blocks() {
+        let parser = InputParser::new();
+        let blocks = parser.parse_blocks(Source::text("\n\n1,2,3\r\n\r\n4 5\r\n6 7\r\n  \r\n\n8 9\n\n\n")).unwrap();
+
+        assert_eq!(blocks, vec![
+            vec!["1,2,3".to_string()],
+            vec!["4 5".to_string(), "6 7".to_string()],
+            vec!["8 9".to_string()],
+        ]);
+        assert!(parser.parse_blocks(Source::text("\n\n")).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_input_parses_comma_separated() {
+        let parser = InputParser::new();
+
+        assert_eq!(parser.parse_comma_separated::<u8, _>(Source::text("3,4,3,1,2\n")).unwrap(), vec![3, 4, 3, 1, 2]);
+        assert_eq!(parser.parse_comma_separated::<i64, _>(Source::text(" 16, -1 ,2\r\n\n0,4\n")).unwrap(), vec![16, -1, 2, 0, 4]);
+        assert_eq!(parser.parse_comma_separated::<u32, _>("input_06.txt").unwrap().len(), 300);
+    }
+
+    #[test]
+    fn test_input_reports_comma_separated_token_positions() {
+        let error = InputParser::new().parse_comma_separated::<u8, _>(Source::text("1,2, x,4\n5,,300")).unwrap_err();
+
+        match error {
+            InputError::Multiple(errors) => {
+                let positions: Vec<(usize, usize, String)> = errors.into_iter().map(|error| match error {
+                    InputError::Parse { line, column, text, .. } => (line, column, text),
+                    other => panic!("Expected Parse but got {:?}.", other),
+                }).collect();
+                assert_eq!(positions, vec![
+                    (1, 6, "x".to_string()),
+                    (2, 3, "".to_string()),
+                    (2, 4, "300".to_string()),
+                ]);
+            },
+            other => panic!("Expected Multiple but got {:?}.", other),
+        }
+    }
+
+    #[test]
+    fn test_input_parses_grid() {
+        let parser = InputParser::new();
+        let grid = parser.parse_as_grid(Source::text("5483143223\n2745854711\n")).unwrap();
+
+        assert_eq!((grid.width(), grid.height()), (10, 2));
+        assert_eq!(grid.get(0, 1), Some(&2));
+
+        let grid = parser.parse_as_grid_with(Source::text("..>\nv.."), |c| "v>.".find(c)).unwrap();
+        assert_eq!(grid.to_string(), "221\n022");
+    }
+
+    #[test]
+    fn test_chunk_reports_shape_mismatch() {
+        let error = InputParser::chunk(vec![1, 2, 3], 2).unwrap_err();
+
+        assert!(matches!(error, InputError::Shape { .. }));
+        assert_eq!(InputParser::chunk(vec![1, 2, 3, 4], 2).unwrap(), vec![vec![1, 2], vec![3, 4]]);
+    }
+}
@@ -1 +1,5 @@
-pub mod input;
\ No newline at end of file
+pub mod error;
+pub mod fetch;
+pub mod grid;
+pub mod input;
+pub mod source;
\ No newline at end of file
@@ -0,0 +1,64 @@
+use std::io::{self, Read};
+
+pub enum Source<'a> {
+    File(&'a str),
+    Text(&'a str),
+    Reader(Box<dyn Read + 'a>),
+    Stdin,
+}
+
+impl<'a> Source<'a> {
+
+    pub fn file(filepath: &'a str) -> Self {
+        Self::File(filepath)
+    }
+
+    pub fn text(text: &'a str) -> Self {
+        Self::Text(text)
+    }
+
+    pub fn reader<R: Read + 'a>(reader: R) -> Self {
+        Self::Reader(Box::new(reader))
+    }
+
+    pub fn stdin() -> Self {
+        Self::Stdin
+    }
+
+    pub fn read_to_string(self) -> io::Result<String> {
+        let mut contents = String::new();
+        match self {
+            Self::File(filepath) => return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("{} must be resolved by an InputParser before reading", filepath)
+            )),
+            Self::Text(text) => contents.push_str(text),
+            Self::Reader(mut reader) => { reader.read_to_string(&mut contents)?; },
+            Self::Stdin => { io::stdin().read_to_string(&mut contents)?; },
+        }
+        Ok(contents)
+    }
+
+    pub fn describe(&self) -> String {
+        match self {
+            Self::File(filepath) => filepath.to_string(),
+            Self::Text(_) => "<text>".to_string(),
+            Self::Reader(_) => "<reader>".to_string(),
+            Self::Stdin => "<stdin>".to_string(),
+        }
+    }
+}
+
+impl<'a> From<&'a str> for Source<'a> {
+
+    fn from(filepath: &'a str) -> Self {
+        Self::File(filepath)
+    }
+}
+
+impl<'a> From<&'a String> for Source<'a> {
+
+    fn from(filepath: &'a String) -> Self {
+        Self::File(filepath)
+    }
+}
@@ -1,6 +1,327 @@
+pub mod alloc;
+pub mod answers;
+pub mod bench;
+pub mod http;
 pub mod input;
+pub mod output;
 pub mod problems;
+pub mod runner;
+pub mod scaffold;
+pub mod submit;
+pub mod watch;
+
+#[cfg(test)]
+mod scratch;
+
+use std::{env, process};
+
+use input::{fetch::{self, FetchOutcome, InputFetcher}, input::InputParser, source::Source};
+use output::format_duration;
+use problems::solution::{Input, Part, Solution};
+use runner::{catch_panic, OutputFormat, RunRecord, RunStatus};
+use scaffold::{Change, Scaffold, Template};
+use submit::{Submitter, SUBMISSION_LOG};
+use watch::Watch;
+
+const USAGE: &str = "Usage:
+    advent-of-code-2021 run --day <N> [--part <a|b>] [--input <path|->] [--input-dir <dir>]
+    advent-of-code-2021 run --all [--input-dir <dir>]
+    advent-of-code-2021 run-all [--part <a|b>] [--format <human|json|csv>] [--input-dir <dir>]
+    advent-of-code-2021 bench [--day <N>] [--part <a|b>] [--iterations <N>] [--json] [--input-dir <dir>]
+    advent-of-code-2021 fetch (--day <N> | --all) [--base-url <url>] [--session <token>] [--input-dir <dir>]
+    advent-of-code-2021 watch --day <N> [--once] [--input-dir <dir>]
+    advent-of-code-2021 new-day --day <N> [--kind <integers|lines|grid|blocks>] [--force] [--input-dir <dir>]
+    advent-of-code-2021 submit --day <N> --part <a|b> [--answer <answer>] [--base-url <url>] [--session <token>] [--input-dir <dir>]
+
+Input files are looked up in --input-dir, then $AOC_INPUT_DIR, then the crate's input/ directory.
+Pass --input - to read the puzzle input from stdin.
+fetch downloads missing inputs into the first of those directories, reading the session token
+from --session or $AOC_SESSION and the server from --base-url or $AOC_BASE_URL; https
+servers are reached through the curl command.
+submit uses the same settings, solves the part when --answer is omitted and logs every attempt
+to submissions.log in that directory, refusing answers the log already rules out.
+new-day writes src/problems/problem_NN.rs from a template, registers it and adds empty example
+and answer entries; it only replaces untouched stubs unless --force is given.
+A part is unimplemented when its module returns no solver for it from part_a/part_b or when the
+solver panics through unimplemented!() or todo!(); any other panic is reported as crashed.
+watch re-runs a day on its example and real input whenever either file or the day's module
+changes, rebuilding through cargo once the source has been edited, and prints what changed.";
+
+#[derive(Debug, Default)]
+struct Options {
+    day: Option<u32>,
+    part: Option<Part>,
+    input: Option<String>,
+    answer: Option<String>,
+    input_dir: Option<String>,
+    base_url: Option<String>,
+    session: Option<String>,
+    iterations: Option<usize>,
+    format: Option<OutputFormat>,
+    kind: Option<Template>,
+    json: bool,
+    all: bool,
+    force: bool,
+    once: bool,
+}
+
+impl Options {
+
+    pub fn parse(args: &[String]) -> Result<Self, String> {
+        let mut options = Self::default();
+        let mut args = args.iter();
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--day" => {
+                    let value = args.next().ok_or("Missing value for --day.")?;
+                    options.day = Some(value.parse::<u32>().map_err(|_| format!("Invalid day: {}.", value))?);
+                },
+                "--part" => {
+                    let value = args.next().ok_or("Missing value for --part.")?;
+                    options.part = Some(Part::parse(value)?);
+                },
+                "--input" => {
+                    let value = args.next().ok_or("Missing value for --input.")?;
+                    options.input = Some(value.to_string());
+                },
+                "--answer" => {
+                    let value = args.next().ok_or("Missing value for --answer.")?;
+                    options.answer = Some(value.to_string());
+                },
+                "--input-dir" => {
+                    let value = args.next().ok_or("Missing value for --input-dir.")?;
+                    options.input_dir = Some(value.to_string());
+                },
+                "--base-url" => {
+                    let value = args.next().ok_or("Missing value for --base-url.")?;
+                    options.base_url = Some(value.to_string());
+                },
+                "--session" => {
+                    let value = args.next().ok_or("Missing value for --session.")?;
+                    options.session = Some(value.to_string());
+                },
+                "--iterations" => {
+                    let value = args.next().ok_or("Missing value for --iterations.")?;
+                    options.iterations = Some(value.parse::<usize>().ok().filter(|n| *n > 0).ok_or(format!("Invalid iteration count: {}.", value))?);
+                },
+                "--format" => {
+                    let value = args.next().ok_or("Missing value for --format.")?;
+                    options.format = Some(OutputFormat::parse(value)?);
+                },
+                "--kind" => {
+                    let value = args.next().ok_or("Missing value for --kind.")?;
+                    options.kind = Some(Template::parse(value)?);
+                },
+                "--json" => options.json = true,
+                "--all" => options.all = true,
+                "--force" => options.force = true,
+                "--once" => options.once = true,
+                _ => return Err(format!("Unexpected argument: {}.", arg)),
+            }
+        }
+        Ok(options)
+    }
+
+    pub fn parser(&self) -> InputParser {
+        match &self.input_dir {
+            Some(dir) => InputParser::with_input_dir(dir),
+            None => InputParser::new(),
+        }
+    }
+
+    pub fn session(&self) -> Result<String, String> {
+        match self.session.clone() {
+            Some(session) => Ok(session),
+            None => env::var(fetch::SESSION_VARIABLE).map_err(|_| format!("No session token: pass --session or set ${}.", fetch::SESSION_VARIABLE)),
+        }
+    }
+
+    pub fn base_url(&self) -> String {
+        self.base_url.clone()
+            .or_else(|| env::var(fetch::BASE_URL_VARIABLE).ok())
+            .unwrap_or(fetch::DEFAULT_BASE_URL.to_string())
+    }
+}
 
 fn main() {
-    println!("Hello, world!");
+    let args: Vec<String> = env::args().skip(1).collect();
+    if let Err(message) = run_command(&args) {
+        eprintln!("{}", message);
+        eprintln!("{}", USAGE);
+        process::exit(1);
+    }
+}
+
+fn run_command(args: &[String]) -> Result<(), String> {
+    match args.first().map(|s| s.as_str()) {
+        Some("run") => run(Options::parse(&args[1..])?),
+        Some("run-all") => run_all(Options::parse(&args[1..])?),
+        Some("bench") => bench(Options::parse(&args[1..])?),
+        Some("fetch") => fetch(Options::parse(&args[1..])?),
+        Some("submit") => submit(Options::parse(&args[1..])?),
+        Some("watch") => watch(Options::parse(&args[1..])?),
+        Some("new-day") => new_day(Options::parse(&args[1..])?),
+        Some(command) => Err(format!("Unknown command: {}.", command)),
+        None => Err("No command given.".to_string()),
+    }
+}
+
+fn run(options: Options) -> Result<(), String> {
+    let parts = match options.part {
+        Some(part) => vec![part],
+        None => Part::both().to_vec(),
+    };
+
+    let parser = options.parser();
+
+    if options.all {
+        if options.day.is_some() || options.input.is_some() {
+            return Err("--all cannot be combined with --day or --input.".to_string());
+        }
+        let records: Vec<RunRecord> = runner::run_all(&parser, &problems::all_solutions(), &parts).into_iter()
+            .filter(|record| record.status != RunStatus::Unimplemented)
+            .collect();
+        print_records(&records);
+        return Ok(());
+    }
+
+    let day = options.day.ok_or("Either --day or --all is required.")?;
+    let solution = problems::get_solution(day).ok_or(format!("No solver for day {}.", day))?;
+    let filepath = match options.input.as_deref() {
+        Some("-") => None,
+        Some(path) => Some(env::current_dir().map_err(|e| e.to_string())?.join(path).to_string_lossy().to_string()),
+        None => Some(solution.input_file()),
+    };
+    let source = match &filepath {
+        Some(filepath) => Source::file(filepath),
+        None => Source::stdin(),
+    };
+    let input = solution.input_kind().load(&parser, source);
+    print_records(&runner::run_input(solution.as_ref(), input, &parts));
+    Ok(())
 }
+
+fn run_all(options: Options) -> Result<(), String> {
+    if options.day.is_some() || options.input.is_some() {
+        return Err("run-all covers every day and cannot be combined with --day or --input.".to_string());
+    }
+    let parts = match options.part {
+        Some(part) => vec![part],
+        None => Part::both().to_vec(),
+    };
+    let records = runner::run_all(&options.parser(), &problems::all_solutions(), &parts);
+    println!("{}", runner::format_records(&records, options.format.unwrap_or(OutputFormat::Human)));
+    Ok(())
+}
+
+fn bench(options: Options) -> Result<(), String> {
+    let parser = options.parser();
+    let solutions = match options.day {
+        Some(day) => vec![problems::get_solution(day).ok_or(format!("No solver for day {}.", day))?],
+        None => problems::all_solutions(),
+    };
+    let parts = match options.part {
+        Some(part) => vec![part],
+        None => Part::both().to_vec(),
+    };
+
+    let mut results = vec![];
+    for solution in solutions {
+        let implemented: Vec<Part> = parts.iter().copied().filter(|part| solution.implemented(*part)).collect();
+        if implemented.is_empty() {
+            continue;
+        }
+        let input = load_input(&parser, solution.as_ref(), Source::file(&solution.input_file()))?;
+        for part in implemented {
+            let result = bench::bench_part(solution.as_ref(), part, &input, options.iterations.unwrap_or(10));
+            if !matches!(&result.outcome, Err(panic) if panic.is_unimplemented()) {
+                results.push(result);
+            }
+        }
+    }
+
+    if options.json {
+        println!("{}", bench::json(&results));
+    } else {
+        println!("{}", bench::table(&results));
+    }
+    Ok(())
+}
+
+fn fetch(options: Options) -> Result<(), String> {
+    let days: Vec<u32> = match (options.all, options.day) {
+        (true, None) => problems::all_solutions().iter().map(|solution| solution.day()).collect(),
+        (false, Some(day)) => vec![day],
+        _ => return Err("fetch needs exactly one of --day or --all.".to_string()),
+    };
+    let fetcher = InputFetcher::new(&options.base_url(), &options.session()?, options.parser().cache_dir())?;
+
+    for day in days {
+        match fetcher.fetch(day)? {
+            FetchOutcome::Cached(path) => println!("Day {:02}: already cached at {}", day, path.display()),
+            FetchOutcome::Downloaded(path) => println!("Day {:02}: downloaded to {}", day, path.display()),
+        }
+    }
+    Ok(())
+}
+
+fn submit(options: Options) -> Result<(), String> {
+    let day = options.day.ok_or("submit needs --day.")?;
+    let part = options.part.ok_or("submit needs --part.")?;
+    let parser = options.parser();
+    let answer = match options.answer.clone() {
+        Some(answer) => answer,
+        None => {
+            let solution = problems::get_solution(day).ok_or(format!("No solver for day {}.", day))?;
+            let input = load_input(&parser, solution.as_ref(), Source::file(&solution.input_file()))?;
+            catch_panic(|| solution.solve(part, input))
+                .map_err(|panic| format!("Day {:02}{} crashed: {}", day, part.letter(), panic))?
+                .submission()
+                .map_err(|error| format!("Day {:02}{} drew an answer that cannot be submitted: {}", day, part.letter(), error))?
+        },
+    };
+
+    let mut submitter = Submitter::new(&options.base_url(), &options.session()?, parser.cache_dir().join(SUBMISSION_LOG))?;
+    let verdict = submitter.submit(day, part, &answer)?;
+    println!("Day {:02}{}: {} -> {}", day, part.letter(), answer.trim(), verdict);
+    Ok(())
+}
+
+fn watch(options: Options) -> Result<(), String> {
+    let day = options.day.ok_or("watch needs --day.")?;
+    let solution = problems::get_solution(day).ok_or(format!("No solver for day {}.", day))?;
+    if options.once {
+        println!("{}", watch::format_results(&watch::evaluate(&options.parser(), solution.as_ref())));
+        return Ok(());
+    }
+    let source_path = Scaffold::new(Scaffold::default_problems_dir(), options.parser().cache_dir()).module_path(day);
+    Watch::new(solution.as_ref(), options.input_dir.clone(), source_path).run()
+}
+
+fn new_day(options: Options) -> Result<(), String> {
+    let day = options.day.ok_or("new-day needs --day.")?;
+    let scaffold = Scaffold::new(Scaffold::default_problems_dir(), options.parser().cache_dir());
+    for change in scaffold.generate(day, options.kind.unwrap_or(Template::Lines), options.force)? {
+        match change {
+            Change::Created(path) => println!("Created {}", path.display()),
+            Change::Overwritten(path) => println!("Replaced {}", path.display()),
+            Change::Updated(path) => println!("Updated {}", path.display()),
+            Change::Unchanged(path) => println!("Left {} unchanged", path.display()),
+        }
+    }
+    Ok(())
+}
+
+fn load_input(parser: &InputParser, solution: &dyn Solution, source: Source) -> Result<Input, String> {
+    solution.input_kind().load(parser, source).map_err(|error| error.to_string())
+}
+
+fn print_records(records: &[RunRecord]) {
+    for record in records {
+        match (&record.answer, record.duration) {
+            (Some(answer), Some(duration)) => println!("Day {:02}{}: {} ({})", record.day, record.part.letter(), answer, format_duration(duration)),
+            _ => println!("Day {:02}{}: {}", record.day, record.part.letter(), record.status),
+        }
+    }
+}
+
@@ -0,0 +1,382 @@
+use std::{cmp::Ordering, fmt, ops::{Add, Mul}, str::FromStr};
+
+use crate::input::grid::Grid;
+use crate::problems::ocr;
+
+const LIMB_BASE: u64 = 1_000_000_000;
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub struct BigUint {
+    limbs: Vec<u32>,
+}
+
+impl BigUint {
+
+    pub fn zero() -> Self {
+        Self { limbs: vec![] }
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.limbs.is_empty()
+    }
+
+    fn normalise(mut self) -> Self {
+        while self.limbs.last() == Some(&0) {
+            self.limbs.pop();
+        }
+        self
+    }
+
+    pub fn to_u64(&self) -> Option<u64> {
+        self.limbs.iter().rev().try_fold(0u64, |value, limb| value.checked_mul(LIMB_BASE)?.checked_add(*limb as u64))
+    }
+}
+
+impl From<u128> for BigUint {
+
+    fn from(mut value: u128) -> Self {
+        let mut limbs = vec![];
+        while value > 0 {
+            limbs.push((value % LIMB_BASE as u128) as u32);
+            value /= LIMB_BASE as u128;
+        }
+        Self { limbs }
+    }
+}
+
+impl From<u64> for BigUint {
+
+    fn from(value: u64) -> Self {
+        Self::from(value as u128)
+    }
+}
+
+impl FromStr for BigUint {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let digits = s.trim().trim_start_matches('+');
+        if digits.is_empty() || !digits.bytes().all(|b| b.is_ascii_digit()) {
+            return Err(format!("Invalid unsigned integer: {:?}.", s));
+        }
+        let limbs = digits.as_bytes().rchunks(9)
+            .map(|chunk| chunk.iter().fold(0u32, |value, digit| value * 10 + (digit - b'0') as u32))
+            .collect();
+        Ok(Self { limbs }.normalise())
+    }
+}
+
+impl Add for &BigUint {
+    type Output = BigUint;
+
+    fn add(self, other: &BigUint) -> BigUint {
+        let mut limbs = Vec::with_capacity(self.limbs.len().max(other.limbs.len()) + 1);
+        let mut carry = 0;
+        for i in 0..self.limbs.len().max(other.limbs.len()) {
+            let sum = *self.limbs.get(i).unwrap_or(&0) as u64 + *other.limbs.get(i).unwrap_or(&0) as u64 + carry;
+            limbs.push((sum % LIMB_BASE) as u32);
+            carry = sum / LIMB_BASE;
+        }
+        if carry > 0 {
+            limbs.push(carry as u32);
+        }
+        BigUint { limbs }
+    }
+}
+
+impl Add for BigUint {
+    type Output = BigUint;
+
+    fn add(self, other: BigUint) -> BigUint {
+        &self + &other
+    }
+}
+
+impl Mul for &BigUint {
+    type Output = BigUint;
+
+    fn mul(self, other: &BigUint) -> BigUint {
+        let mut limbs = vec![0u64; self.limbs.len() + other.limbs.len()];
+        for (i, a) in self.limbs.iter().enumerate() {
+            let mut carry = 0;
+            for (j, b) in other.limbs.iter().enumerate() {
+                let product = limbs[i + j] + *a as u64 * *b as u64 + carry;
+                limbs[i + j] = product % LIMB_BASE;
+                carry = product / LIMB_BASE;
+            }
+            let mut k = i + other.limbs.len();
+            while carry > 0 {
+                let sum = limbs[k] + carry;
+                limbs[k] = sum % LIMB_BASE;
+                carry = sum / LIMB_BASE;
+                k += 1;
+            }
+        }
+        BigUint { limbs: limbs.into_iter().map(|limb| limb as u32).collect() }.normalise()
+    }
+}
+
+impl Mul for BigUint {
+    type Output = BigUint;
+
+    fn mul(self, other: BigUint) -> BigUint {
+        &self * &other
+    }
+}
+
+impl Ord for BigUint {
+
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.limbs.len().cmp(&other.limbs.len()).then_with(|| self.limbs.iter().rev().cmp(other.limbs.iter().rev()))
+    }
+}
+
+impl PartialOrd for BigUint {
+
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl fmt::Display for BigUint {
+
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.limbs.split_last() {
+            None => write!(f, "0"),
+            Some((most, rest)) => {
+                write!(f, "{}", most)?;
+                for limb in rest.iter().rev() {
+                    write!(f, "{:09}", limb)?;
+                }
+                Ok(())
+            },
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Answer {
+    Signed(i64),
+    Unsigned(u64),
+    Big(BigUint),
+    Text(String),
+    Art(Vec<String>),
+}
+
+impl Answer {
+
+    pub fn art<S: AsRef<str>>(rows: &[S]) -> Self {
+        Self::Art(rows.iter().map(|row| row.as_ref().trim_end().to_string()).collect())
+    }
+
+    pub fn submission(&self) -> Result<String, String> {
+        match self {
+            Self::Art(rows) => ocr::recognise_rows(rows),
+            answer => Ok(answer.to_string()),
+        }
+    }
+
+    pub fn matches(&self, expected: &str) -> bool {
+        let expected = expected.trim();
+        match self {
+            Self::Signed(value) => expected.parse::<i64>().is_ok_and(|expected| expected == *value),
+            Self::Unsigned(value) => expected.trim_start_matches('+').parse::<u64>().is_ok_and(|expected| expected == *value),
+            Self::Big(value) => expected.parse::<BigUint>().is_ok_and(|expected| expected == *value),
+            Self::Text(text) => text.trim() == expected,
+            Self::Art(rows) => {
+                if ocr::recognise_rows(rows).is_ok_and(|text| text == expected) {
+                    return true;
+                }
+                let expected: Vec<&str> = expected.lines().map(|row| row.trim()).filter(|row| !row.is_empty()).collect();
+                rows.iter().map(|row| row.trim()).filter(|row| !row.is_empty()).eq(expected)
+            },
+        }
+    }
+}
+
+impl fmt::Display for Answer {
+
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Signed(value) => write!(f, "{}", value),
+            Self::Unsigned(value) => write!(f, "{}", value),
+            Self::Big(value) => write!(f, "{}", value),
+            Self::Text(text) => write!(f, "{}", text),
+            Self::Art(rows) => write!(f, "\n{}", rows.join("\n")),
+        }
+    }
+}
+
+impl PartialEq<str> for Answer {
+
+    fn eq(&self, expected: &str) -> bool {
+        self.matches(expected)
+    }
+}
+
+impl PartialEq<&str> for Answer {
+
+    fn eq(&self, expected: &&str) -> bool {
+        self.matches(expected)
+    }
+}
+
+macro_rules! answer_from {
+    ($variant:ident, $target:ty, $($source:ty),*) => {
+        $(
+            impl From<$source> for Answer {
+
+                fn from(value: $source) -> Self {
+                    Self::$variant(value as $target)
+                }
+            }
+        )*
+    };
+}
+
+answer_from!(Signed, i64, i8, i16, i32, i64, isize);
+answer_from!(Unsigned, u64, u8, u16, u32, u64, usize);
+
+impl From<u128> for Answer {
+
+    fn from(value: u128) -> Self {
+        match u64::try_from(value) {
+            Ok(value) => Self::Unsigned(value),
+            Err(_) => Self::Big(BigUint::from(value)),
+        }
+    }
+}
+
+impl From<BigUint> for Answer {
+
+    fn from(value: BigUint) -> Self {
+        match value.to_u64() {
+            Some(value) => Self::Unsigned(value),
+            None => Self::Big(value),
+        }
+    }
+}
+
+impl From<Grid<bool>> for Answer {
+
+    fn from(grid: Grid<bool>) -> Self {
+        Self::Art(grid.rows().map(|row| row.iter().map(|lit| if *lit { '#' } else { '.' }).collect()).collect())
+    }
+}
+
+impl From<String> for Answer {
+
+    fn from(text: String) -> Self {
+        Self::Text(text)
+    }
+}
+
+impl From<&str> for Answer {
+
+    fn from(text: &str) -> Self {
+        Self::Text(text.to_string())
+    }
+}
+
+#[cfg(test)]
+mod test_answer {
+    use super::*;
+
+    #[test]
+    fn test_big_uint_arithmetic() {
+        let a: BigUint = "26984457539".parse().unwrap();
+        let b = BigUint::from(u64::MAX);
+
+        assert_eq!((&a + &b).to_string(), "18446744100694009154");
+        assert_eq!((&b * &b).to_string(), "340282366920938463426481119284349108225");
+        assert_eq!((&a * &BigUint::zero()).to_string(), "0");
+        assert_eq!("000123".parse::<BigUint>().unwrap().to_string(), "123");
+        assert!(a < b && (&b * &b) > b);
+        assert!("12a".parse::<BigUint>().is_err());
+    }
+
+    #[test]
+    fn test_big_uint_carries_across_limbs() {
+        let limb_max: BigUint = "999999999".parse().unwrap();
+        let one = BigUint::from(1u64);
+
+        assert_eq!((&limb_max + &one).to_string(), "1000000000");
+        assert_eq!(("999999999999999999999999999".parse::<BigUint>().unwrap() + one.clone()).to_string(), "1000000000000000000000000000");
+        assert_eq!((&limb_max * &limb_max).to_string(), "999999998000000001");
+        assert_eq!(("999999999999999999".parse::<BigUint>().unwrap() * "999999999999999999".parse().unwrap()).to_string(), "999999999999999998000000000000000001");
+        assert_eq!(("1000000000000000001".parse::<BigUint>().unwrap() + one).to_string(), "1000000000000000002");
+        assert_eq!(BigUint::from(1_000_000_000u64).to_u64(), Some(1_000_000_000));
+    }
+
+    #[test]
+    fn test_big_uint_zero() {
+        let zero = BigUint::zero();
+
+        assert!(zero.is_zero());
+        assert_eq!(zero.to_string(), "0");
+        assert_eq!(zero.to_u64(), Some(0));
+        assert_eq!(BigUint::from(0u64), zero);
+        assert_eq!("0".parse::<BigUint>().unwrap(), zero);
+        assert_eq!(&zero + &zero, zero);
+        assert_eq!(&BigUint::from(u64::MAX) * &zero, zero);
+        assert!(zero < BigUint::from(1u64));
+    }
+
+    #[test]
+    fn test_big_uint_parses_leading_zeros() {
+        assert!("0000000000000000000".parse::<BigUint>().unwrap().is_zero());
+        assert_eq!("0000000001000000000".parse::<BigUint>().unwrap(), BigUint::from(1_000_000_000u64));
+        assert_eq!("+007".parse::<BigUint>().unwrap().to_string(), "7");
+        assert_eq!(" 000000000000000000026984457539\n".parse::<BigUint>().unwrap().to_string(), "26984457539");
+        assert!("".parse::<BigUint>().is_err());
+        assert!("-0".parse::<BigUint>().is_err());
+    }
+
+    #[test]
+    fn test_answers_compare_with_expected_text() {
+        assert!(Answer::from(1548usize) == "1548");
+        assert!(Answer::from(-12i32) == " -12\n");
+        assert!(Answer::from(26984457539u64) == "26984457539");
+        assert!(Answer::from(u128::MAX) == "340282366920938463463374607431768211455");
+        assert!(Answer::from("HZKHFEJZ").matches("HZKHFEJZ\n"));
+        assert!(Answer::from(7u32) != "8");
+        assert!(Answer::from(7u32) != "seven");
+    }
+
+    #[test]
+    fn test_art_displays_rows_and_ignores_padding() {
+        let art = Answer::art(&["#..#  ", "####", "#..#"]);
+
+        assert_eq!(art.to_string(), "\n#..#\n####\n#..#");
+        assert!(art.matches("\n  #..#\n  ####\n  #..#\n"));
+        assert!(!art.matches("#..#\n#..#"));
+    }
+
+    #[test]
+    fn test_art_matches_the_letters_it_draws() {
+        let rows = ["#..#.####", "#..#....#", "####...#.", "#..#..#..", "#..#.#...", "#..#.####"];
+        let grid = Grid::from_rows(rows.iter().map(|row| row.chars().map(|c| c == '#').collect()).collect()).unwrap();
+        let art = Answer::from(grid);
+
+        assert!(art.matches("HZ"));
+        assert!(!art.matches("HE"));
+        assert_eq!(art, Answer::art(&rows));
+    }
+
+    #[test]
+    fn test_art_is_submitted_as_the_letters_it_draws() {
+        let rows = ["#..#.####", "#..#....#", "####...#.", "#..#..#..", "#..#.#...", "#..#.####"];
+
+        assert_eq!(Answer::art(&rows).submission().unwrap(), "HZ");
+        assert_eq!(Answer::from(1548usize).submission().unwrap(), "1548");
+        assert!(Answer::art(&["#..#", "####"]).submission().is_err());
+    }
+
+    #[test]
+    fn test_conversions_pick_the_narrowest_variant() {
+        assert_eq!(Answer::from(5i32), Answer::Signed(5));
+        assert_eq!(Answer::from(5usize), Answer::Unsigned(5));
+        assert_eq!(Answer::from(BigUint::from(5u64)), Answer::Unsigned(5));
+        assert!(matches!(Answer::from(u64::MAX as u128 + 1), Answer::Big(_)));
+    }
+}
@@ -1,3 +1,5 @@
+pub mod answer;
+pub mod ocr;
 pub mod problem_01;
 pub mod problem_02;
 pub mod problem_03;
@@ -23,3 +25,66 @@ pub mod problem_22;
 pub mod problem_23;
 pub mod problem_24;
 pub mod problem_25;
+pub mod solution;
+
+use solution::Solution;
+
+pub fn all_solutions() -> Vec<Box<dyn Solution>> {
+    vec![
+        Box::new(problem_01::Problem01),
+        Box::new(problem_02::Problem02),
+        Box::new(problem_03::Problem03),
+        Box::new(problem_04::Problem04),
+        Box::new(problem_05::Problem05),
+        Box::new(problem_06::Problem06),
+        Box::new(problem_07::Problem07),
+        Box::new(problem_08::Problem08),
+        Box::new(problem_09::Problem09),
+        Box::new(problem_10::Problem10),
+        Box::new(problem_11::Problem11),
+        Box::new(problem_12::Problem12),
+        Box::new(problem_13::Problem13),
+        Box::new(problem_14::Problem14),
+        Box::new(problem_15::Problem15),
+        Box::new(problem_16::Problem16),
+        Box::new(problem_17::Problem17),
+        Box::new(problem_18::Problem18),
+        Box::new(problem_19::Problem19),
+        Box::new(problem_20::Problem20),
+        Box::new(problem_21::Problem21),
+        Box::new(problem_22::Problem22),
+        Box::new(problem_23::Problem23),
+        Box::new(problem_24::Problem24),
+        Box::new(problem_25::Problem25),
+    ]
+}
+
+pub fn get_solution(day: u32) -> Option<Box<dyn Solution>> {
+    all_solutions().into_iter().find(|solution| solution.day() == day)
+}
+
+#[cfg(test)]
+mod test_problems {
+
+    use super::*;
+
+    #[test]
+    fn test_registry_covers_every_day_in_order() {
+        let days: Vec<u32> = all_solutions().iter().map(|solution| solution.day()).collect();
+        assert_eq!(days, (1..=25).collect::<Vec<u32>>());
+
+        assert!(get_solution(1).is_some());
+        assert!(get_solution(26).is_none());
+    }
+
+    #[test]
+    fn test_registry_reports_unimplemented_parts() {
+        let solution = get_solution(1).unwrap();
+        assert!(solution.implemented(solution::Part::A));
+        assert!(solution.implemented(solution::Part::B));
+
+        let solution = get_solution(7).unwrap();
+        assert!(!solution.implemented(solution::Part::A));
+        assert!(!solution.implemented(solution::Part::B));
+    }
+}
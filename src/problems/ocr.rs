@@ -0,0 +1,138 @@
+use crate::input::grid::Grid;
+
+pub const GLYPH_WIDTH: usize = 4;
+pub const GLYPH_HEIGHT: usize = 6;
+
+const ALPHABET: [(char, [&str; GLYPH_HEIGHT]); 17] = [
+    ('A', [".##.", "#..#", "#..#", "####", "#..#", "#..#"]),
+    ('B', ["###.", "#..#", "###.", "#..#", "#..#", "###."]),
+    ('C', [".##.", "#..#", "#...", "#...", "#..#", ".##."]),
+    ('E', ["####", "#...", "###.", "#...", "#...", "####"]),
+    ('F', ["####", "#...", "###.", "#...", "#...", "#..."]),
+    ('G', [".##.", "#..#", "#...", "#.##", "#..#", ".###"]),
+    ('H', ["#..#", "#..#", "####", "#..#", "#..#", "#..#"]),
+    ('I', [".###", "..#.", "..#.", "..#.", "..#.", ".###"]),
+    ('J', ["..##", "...#", "...#", "...#", "#..#", ".##."]),
+    ('K', ["#..#", "#.#.", "##..", "#.#.", "#.#.", "#..#"]),
+    ('L', ["#...", "#...", "#...", "#...", "#...", "####"]),
+    ('O', [".##.", "#..#", "#..#", "#..#", "#..#", ".##."]),
+    ('P', ["###.", "#..#", "#..#", "###.", "#...", "#..."]),
+    ('R', ["###.", "#..#", "#..#", "###.", "#.#.", "#..#"]),
+    ('S', [".###", "#...", "#...", ".##.", "...#", "###."]),
+    ('U', ["#..#", "#..#", "#..#", "#..#", "#..#", ".##."]),
+    ('Z', ["####", "...#", "..#.", ".#..", "#...", "####"]),
+];
+
+pub fn recognise(grid: &Grid<bool>) -> Result<String, String> {
+    let lit_rows: Vec<usize> = (0..grid.height()).filter(|y| grid.row(*y).iter().any(|lit| *lit)).collect();
+    let (top, bottom) = match (lit_rows.first(), lit_rows.last()) {
+        (Some(top), Some(bottom)) => (*top, *bottom),
+        _ => return Err("Nothing is drawn in the grid.".to_string()),
+    };
+    if bottom - top + 1 != GLYPH_HEIGHT {
+        return Err(format!("Expected letters {} rows tall but the drawing spans {} rows.", GLYPH_HEIGHT, bottom - top + 1));
+    }
+    let mut runs: Vec<(usize, usize)> = vec![];
+    for x in (0..grid.width()).filter(|x| grid.column(*x).any(|lit| *lit)) {
+        match runs.last_mut() {
+            Some((_, end)) if *end == x => *end = x + 1,
+            _ => runs.push((x, x + 1)),
+        }
+    }
+
+    let mut text = String::new();
+    let mut unrecognised = vec![];
+    for (i, (left, right)) in runs.into_iter().enumerate() {
+        let pattern: Vec<String> = (top..=bottom)
+            .map(|y| (left..right).map(|x| if grid.get(x, y) == Some(&true) { '#' } else { '.' }).collect())
+            .collect();
+        match ALPHABET.iter().find(|(_, glyph)| trim_blank_columns(glyph) == pattern) {
+            Some((letter, _)) => text.push(*letter),
+            None => {
+                text.push('?');
+                unrecognised.push(format!("  glyph {} at column {}:\n{}", i + 1, left, pattern.iter().map(|row| format!("    {}", row)).collect::<Vec<_>>().join("\n")));
+            },
+        }
+    }
+    if !unrecognised.is_empty() {
+        return Err(format!("Unable to read {:?}; {} unrecognised glyph(s):\n{}", text, unrecognised.len(), unrecognised.join("\n")));
+    }
+    Ok(text)
+}
+
+fn trim_blank_columns(glyph: &[&str; GLYPH_HEIGHT]) -> Vec<String> {
+    let lit = |x: usize| glyph.iter().any(|row| row.as_bytes()[x] == b'#');
+    let left = (0..GLYPH_WIDTH).find(|x| lit(*x)).unwrap_or(0);
+    let right = (0..GLYPH_WIDTH).rfind(|x| lit(*x)).map_or(left, |x| x + 1);
+    glyph.iter().map(|row| row[left..right].to_string()).collect()
+}
+
+pub fn recognise_rows<S: AsRef<str>>(rows: &[S]) -> Result<String, String> {
+    let width = rows.iter().map(|row| row.as_ref().chars().count()).max().unwrap_or(0);
+    let cells: Vec<Vec<bool>> = rows.iter()
+        .map(|row| {
+            let mut cells: Vec<bool> = row.as_ref().chars().map(|c| c == '#').collect();
+            cells.resize(width, false);
+            cells
+        })
+        .collect();
+    let grid = Grid::from_rows(cells).map_err(|error| error.to_string())?;
+    recognise(&grid)
+}
+
+#[cfg(test)]
+mod test_ocr {
+    use super::*;
+
+    fn render(text: &str) -> Vec<String> {
+        (0..GLYPH_HEIGHT).map(|y| text.chars()
+            .map(|letter| ALPHABET.iter().find(|(c, _)| *c == letter).unwrap().1[y])
+            .collect::<Vec<_>>()
+            .join(".")
+        ).collect()
+    }
+
+    #[test]
+    fn test_recognises_every_letter() {
+        let letters: String = ALPHABET.iter().map(|(letter, _)| *letter).collect();
+        assert_eq!(recognise_rows(&render(&letters)).unwrap(), letters);
+    }
+
+    #[test]
+    fn test_ignores_blank_margins() {
+        let mut rows = vec!["".to_string()];
+        rows.extend(render("HZ").into_iter().map(|row| format!("{}.....", row)));
+        rows.push("..........".to_string());
+        assert_eq!(recognise_rows(&rows).unwrap(), "HZ");
+
+        for margin in 1..=5 {
+            let indented: Vec<String> = rows.iter().map(|row| format!("{}{}", ".".repeat(margin), row)).collect();
+            assert_eq!(recognise_rows(&indented).unwrap(), "HZ");
+        }
+    }
+
+    #[test]
+    fn test_reads_narrow_glyphs_behind_any_margin() {
+        for text in ["IH", "JIZ", "HIJ"] {
+            for margin in 0..=4 {
+                let indented: Vec<String> = render(text).iter().map(|row| format!("{}{}", ".".repeat(margin), row)).collect();
+                assert_eq!(recognise_rows(&indented).unwrap(), text, "margin {}", margin);
+            }
+        }
+    }
+
+    #[test]
+    fn test_lists_unrecognised_glyphs() {
+        let mut rows = render("EAE");
+        rows[0].replace_range(5..9, "####");
+        let error = recognise_rows(&rows).unwrap_err();
+
+        assert!(error.contains("\"E?E\""), "{}", error);
+        assert!(error.contains("glyph 2 at column 5"), "{}", error);
+        assert!(error.contains("    ####\n    #..#"), "{}", error);
+
+        let indented: Vec<String> = rows.iter().map(|row| format!("..{}", row)).collect();
+        assert!(recognise_rows(&indented).unwrap_err().contains("glyph 2 at column 7"));
+        assert!(recognise_rows(&["#..#", "####"]).unwrap_err().contains("6 rows"));
+    }
+}
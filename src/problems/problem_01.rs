@@ -1,12 +1,235 @@
-use crate::input::input::InputParser;
+use std::{collections::VecDeque, fmt, iter::Sum};
+
+use crate::problems::solution::{InputKind, Solution, Solver};
 
 pub fn solve_problem_01a(input: Vec<i32>) -> usize {
-    input.iter().zip(input.iter().skip(1)).filter(|(a, b)| a < b).count()
+    count_increases(input, 1, 1)
+}
+
+pub fn solve_problem_01b(input: Vec<i32>) -> usize {
+    count_increases(input, 3, 1)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WindowChange<T> {
+    pub index: usize,
+    pub previous: T,
+    pub current: T,
+}
+
+impl<T: PartialOrd> WindowChange<T> {
+
+    pub fn is_increase(&self) -> bool {
+        self.current > self.previous
+    }
+}
+
+pub struct WindowChanges<I: Iterator> {
+    depths: I,
+    window: usize,
+    lag: usize,
+    buffer: VecDeque<I::Item>,
+    consumed: usize,
+}
+
+impl<I, T> Iterator for WindowChanges<I>
+where
+    I: Iterator<Item = T>,
+    T: Copy + Sum<T>,
+{
+    type Item = WindowChange<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.buffer.len() < self.window + self.lag {
+            self.buffer.push_back(self.depths.next()?);
+            self.consumed += 1;
+        }
+        let change = WindowChange {
+            index: self.consumed - self.window,
+            previous: self.buffer.iter().take(self.window).copied().sum(),
+            current: self.buffer.iter().skip(self.lag).copied().sum(),
+        };
+        self.buffer.pop_front();
+        Some(change)
+    }
 }
 
-fn solve_problem_01b(input: Vec<i32>) -> usize {
-    let windows: Vec<i32> = input.iter().zip(input.iter().skip(1)).zip(input.iter().skip(2)).map(|((a, b), c)| a + b + c).collect();
-    solve_problem_01a(windows)
+pub fn window_changes<I, T>(depths: I, window: usize, lag: usize) -> WindowChanges<I::IntoIter>
+where
+    I: IntoIterator<Item = T>,
+    T: Copy + Sum<T>,
+{
+    assert!(window > 0 && lag > 0, "Sonar windows and lags must both be at least 1.");
+    WindowChanges { depths: depths.into_iter(), window, lag, buffer: VecDeque::with_capacity(window + lag), consumed: 0 }
+}
+
+pub fn increase_indices<I, T>(depths: I, window: usize, lag: usize) -> impl Iterator<Item = usize>
+where
+    I: IntoIterator<Item = T>,
+    T: Copy + Sum<T> + PartialOrd,
+{
+    window_changes(depths, window, lag).filter(WindowChange::is_increase).map(|change| change.index)
+}
+
+pub fn count_increases<I, T>(depths: I, window: usize, lag: usize) -> usize
+where
+    I: IntoIterator<Item = T>,
+    T: Copy + Sum<T> + PartialOrd,
+{
+    increase_indices(depths, window, lag).count()
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Run {
+    pub start: usize,
+    pub length: usize,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Jump<T> {
+    pub index: usize,
+    pub from: T,
+    pub to: T,
+}
+
+impl<T: Copy + Into<f64>> Jump<T> {
+
+    pub fn change(&self) -> f64 {
+        self.to.into() - self.from.into()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Anomaly<T> {
+    pub index: usize,
+    pub depth: T,
+    pub expected: f64,
+}
+
+impl<T: Copy + Into<f64>> Anomaly<T> {
+
+    pub fn deviation(&self) -> f64 {
+        self.depth.into() - self.expected
+    }
+}
+
+pub fn longest_increasing_run<T: PartialOrd>(values: &[T]) -> Option<Run> {
+    let mut longest: Option<Run> = None;
+    let mut start = 0;
+    for end in 1..=values.len() {
+        if end == values.len() || values[end] <= values[end - 1] {
+            let run = Run { start, length: end - start };
+            if longest.is_none_or(|longest| run.length > longest.length) {
+                longest = Some(run);
+            }
+            start = end;
+        }
+    }
+    longest
+}
+
+pub fn largest_jump<T: Copy + Into<f64>>(depths: &[T]) -> Option<Jump<T>> {
+    depths.windows(2).enumerate()
+        .map(|(i, pair)| Jump { index: i + 1, from: pair[0], to: pair[1] })
+        .fold(None, |largest: Option<Jump<T>>, jump| match largest {
+            Some(largest) if largest.change().abs() >= jump.change().abs() => Some(largest),
+            _ => Some(jump),
+        })
+}
+
+pub fn moving_average<T: Copy + Into<f64>>(depths: &[T], window: usize) -> Vec<f64> {
+    assert!(window > 0, "Moving averages need a window of at least 1.");
+    if depths.len() < window {
+        return vec![];
+    }
+    let mut sum: f64 = depths[..window].iter().map(|depth| (*depth).into()).sum();
+    let mut averages = vec![sum / window as f64];
+    for i in window..depths.len() {
+        sum += depths[i].into() - depths[i - window].into();
+        averages.push(sum / window as f64);
+    }
+    averages
+}
+
+pub fn anomalies<T: Copy + Into<f64>>(depths: &[T], window: usize, threshold: f64) -> Vec<Anomaly<T>> {
+    moving_average(depths, window).into_iter()
+        .zip(depths.iter().enumerate().skip(window))
+        .map(|(expected, (index, depth))| Anomaly { index, depth: *depth, expected })
+        .filter(|anomaly| anomaly.deviation().abs() > threshold)
+        .collect()
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct DepthProfile<T> {
+    pub readings: usize,
+    pub window: usize,
+    pub threshold: f64,
+    pub longest_increasing_run: Option<Run>,
+    pub largest_jump: Option<Jump<T>>,
+    pub moving_average: Vec<f64>,
+    pub anomalies: Vec<Anomaly<T>>,
+}
+
+impl<T: Copy + PartialOrd + Into<f64>> DepthProfile<T> {
+
+    pub fn new(depths: &[T], window: usize, threshold: f64) -> Self {
+        Self {
+            readings: depths.len(),
+            window,
+            threshold,
+            longest_increasing_run: longest_increasing_run(depths),
+            largest_jump: largest_jump(depths),
+            moving_average: moving_average(depths, window),
+            anomalies: anomalies(depths, window, threshold),
+        }
+    }
+}
+
+impl<T: Copy + Into<f64> + fmt::Display> fmt::Display for DepthProfile<T> {
+
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Readings: {}", self.readings)?;
+        match self.longest_increasing_run {
+            Some(run) => writeln!(f, "Longest increasing run: {} readings from index {}", run.length, run.start)?,
+            None => writeln!(f, "Longest increasing run: none")?,
+        }
+        match self.largest_jump {
+            Some(jump) => writeln!(f, "Largest jump: {:+} at index {} ({} -> {})", jump.change(), jump.index, jump.from, jump.to)?,
+            None => writeln!(f, "Largest jump: none")?,
+        }
+        let lowest = self.moving_average.iter().copied().reduce(f64::min);
+        let highest = self.moving_average.iter().copied().reduce(f64::max);
+        match (lowest, highest) {
+            (Some(lowest), Some(highest)) => writeln!(f, "Moving average ({}): {:.2} to {:.2}", self.window, lowest, highest)?,
+            _ => writeln!(f, "Moving average ({}): not enough readings", self.window)?,
+        }
+        write!(f, "Anomalies beyond {}: {}", self.threshold, self.anomalies.len())?;
+        for anomaly in &self.anomalies {
+            write!(f, "\n  index {}: {} vs {:.2} ({:+.2})", anomaly.index, anomaly.depth, anomaly.expected, anomaly.deviation())?;
+        }
+        Ok(())
+    }
+}
+
+pub struct Problem01;
+
+impl Solution for Problem01 {
+
+    fn day(&self) -> u32 {
+        1
+    }
+
+    fn input_kind(&self) -> InputKind {
+        InputKind::Integers
+    }
+
+    fn part_a(&self) -> Option<Solver> {
+        Some(|input| solve_problem_01a(input.into_integers()).into())
+    }
+
+    fn part_b(&self) -> Option<Solver> {
+        Some(|input| solve_problem_01b(input.into_integers()).into())
+    }
 }
 
 #[cfg(test)]
@@ -14,28 +237,65 @@ mod test_problem_01 {
 
     use super::*;
 
+    const EXAMPLE: [i32; 10] = [199, 200, 208, 210, 200, 207, 240, 269, 260, 263];
+
     #[test]
-    fn test_problem_01a_passes() {
-        
-        let input = InputParser::new().parse_as_i32("input_01.txt").unwrap();
-        let shorted_input = input.iter().take(10).map(|i| *i).collect();
+    fn test_window_changes_match_both_parts_on_the_example() {
+        assert_eq!(count_increases(EXAMPLE, 1, 1), 7);
+        assert_eq!(count_increases(EXAMPLE, 3, 1), 5);
+        assert_eq!(increase_indices(EXAMPLE, 1, 1).collect::<Vec<_>>(), vec![1, 2, 3, 5, 6, 7, 9]);
 
-        assert_eq!(solve_problem_01a(shorted_input), 6);
+        let first = window_changes(EXAMPLE, 3, 1).next().unwrap();
+        assert_eq!(first, WindowChange { index: 1, previous: 607, current: 618 });
+    }
 
-        let answer = solve_problem_01a(input);
-        assert_eq!(answer, 1549);
+    #[test]
+    fn test_window_changes_support_any_lag_and_numeric_type() {
+        assert_eq!(count_increases(EXAMPLE, 1, 3), count_increases(EXAMPLE, 3, 1));
+        assert_eq!(increase_indices(EXAMPLE, 2, 4).collect::<Vec<_>>(), vec![4, 5, 6, 7, 8]);
+
+        let floats = EXAMPLE.iter().map(|depth| *depth as f64 / 10.0);
+        assert_eq!(count_increases(floats, 3, 1), 5);
+        assert_eq!(count_increases([5u8, 4], 1, 1), 0);
+        assert_eq!(count_increases([1u64, 2], 3, 1), 0);
     }
-    
+
+    #[test]
+    fn test_window_changes_stream_without_collecting() {
+        let sawtooth = (0u64..).map(|i| i % 3);
+        assert_eq!(increase_indices(sawtooth, 1, 1).take(4).collect::<Vec<_>>(), vec![1, 2, 4, 5]);
+    }
+
     #[test]
-    fn test_problem_01b_passes() {
-        
-        let input = InputParser::new().parse_as_i32("input_01.txt").unwrap();
-        let shortened_input = input.iter().take(10).map(|i| *i).collect();
+    fn test_sequence_utilities_on_the_example() {
+        assert_eq!(longest_increasing_run(&EXAMPLE), Some(Run { start: 0, length: 4 }));
+        assert_eq!(longest_increasing_run(&[3, 2, 1]), Some(Run { start: 0, length: 1 }));
+        assert_eq!(longest_increasing_run::<i32>(&[]), None);
 
-        assert_eq!(solve_problem_01b(shortened_input), 4);
+        assert_eq!(largest_jump(&EXAMPLE), Some(Jump { index: 6, from: 207, to: 240 }));
+        assert_eq!(largest_jump(&[5, -40, -10]).unwrap().change(), -45.0);
+        assert_eq!(largest_jump(&[5]), None);
+        assert_eq!(largest_jump(&[1.5, 0.25, 2.0]), Some(Jump { index: 2, from: 0.25, to: 2.0 }));
+        assert_eq!(largest_jump(&[3u8, 250, 1]).unwrap().change(), -249.0);
+
+        let averages: Vec<String> = moving_average(&EXAMPLE, 3).iter().map(|average| format!("{:.2}", average)).collect();
+        assert_eq!(averages, vec!["202.33", "206.00", "206.00", "205.67", "215.67", "238.67", "256.33", "264.00"]);
+        assert!(moving_average(&EXAMPLE[..2], 3).is_empty());
+        assert_eq!(moving_average(&[1u32, 2, 4, 8], 2), vec![1.5, 3.0, 6.0]);
+        assert_eq!(anomalies(&[1.0, 1.0, 9.0, 1.0], 2, 4.0), vec![Anomaly { index: 2, depth: 9.0, expected: 1.0 }]);
+    }
+
+    #[test]
+    fn test_depth_profile_reports_anomalies() {
+        let profile = DepthProfile::new(&EXAMPLE, 3, 20.0);
+        assert_eq!(profile.anomalies.iter().map(|anomaly| anomaly.index).collect::<Vec<_>>(), vec![6, 7, 8]);
+        assert!(DepthProfile::new(&EXAMPLE, 3, 60.0).anomalies.is_empty());
 
-        let answer = solve_problem_01b(input);
-        assert_eq!(answer, 1589);
+        let report = profile.to_string();
+        assert!(report.contains("Longest increasing run: 4 readings from index 0"), "{}", report);
+        assert!(report.contains("Largest jump: +33 at index 6 (207 -> 240)"), "{}", report);
+        assert!(report.contains("Moving average (3): 202.33 to 264.00"), "{}", report);
+        assert!(report.ends_with("Anomalies beyond 20: 3\n  index 6: 240 vs 205.67 (+34.33)\n  index 7: 269 vs 215.67 (+53.33)\n  index 8: 260 vs 238.67 (+21.33)"), "{}", report);
     }
 
     #[test]
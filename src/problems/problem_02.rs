@@ -1,18 +1,20 @@
-use crate::input::input::InputParser;
+use std::{fmt::Write, str::FromStr};
 
-enum SubmarineCommand {
+use crate::input::{error::InputError, input::InputParser, source::Source};
+use crate::problems::solution::{InputKind, Solution, Solver};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SubmarineCommand {
     Forward(i32),
     Up(i32),
     Down(i32),
+    Back(i32),
 }
 
-impl SubmarineCommand {
-
-    pub fn parse_commands(command_strings: Vec<String>) -> Result<Vec<Self>, String> {
-        command_strings.iter().map(|c| Self::parse_command(c.to_string())).collect()
-    }
+impl FromStr for SubmarineCommand {
+    type Err = String;
 
-    pub fn parse_command(s: String) -> Result<Self, String> {
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
         let words = s.split_whitespace().collect::<Vec<&str>>();
         if words.len() != 2 {
             return Err(format!("Invalid command length: {}.", words.len()));
@@ -24,37 +26,393 @@ impl SubmarineCommand {
             "forward" => Ok(Self::Forward(value)),
             "up" => Ok(Self::Up(value)),
             "down" => Ok(Self::Down(value)),
+            "back" => Ok(Self::Back(value)),
             _ => Err(format!("Invalid command: {}.", command)),
         }
     }
 }
 
-fn solve_problem_02a(input: Vec<String>) -> i32 {
-    let commands = SubmarineCommand::parse_commands(input).unwrap();
-    let (total_forward, total_down): (i32, i32) = commands.into_iter().map(|c| {
-        match c {
-            SubmarineCommand::Forward(n) => (n, 0),
-            SubmarineCommand::Down(n) => (0, n),
-            SubmarineCommand::Up(n) => (0, -n),
+pub const MAX_REPEAT_DEPTH: usize = 64;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CourseStatement {
+    Command(SubmarineCommand),
+    Repeat(u32, Vec<CourseStatement>),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CourseScript {
+    pub statements: Vec<CourseStatement>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum TokenKind {
+    Word,
+    Number,
+    Open,
+    Close,
+}
+
+#[derive(Debug, Clone)]
+struct Token {
+    kind: TokenKind,
+    text: String,
+    line: usize,
+    column: usize,
+}
+
+impl CourseScript {
+
+    pub fn load<'a, S: Into<Source<'a>>>(parser: &InputParser, source: S) -> Result<Self, InputError> {
+        parser.parse_with(source, Self::parse)
+    }
+
+    pub fn parse(origin: &str, lines: &[String]) -> Result<Self, InputError> {
+        let tokens = Self::tokenize(origin, lines)?;
+        let mut position = 0;
+        let statements = Self::parse_block(origin, lines, &tokens, &mut position, None, 0)?;
+        Ok(Self { statements })
+    }
+
+    pub fn commands(&self) -> CourseCommands<'_> {
+        CourseCommands { stack: vec![Frame { statements: &self.statements, index: 0, remaining: 1 }] }
+    }
+
+    fn tokenize(origin: &str, lines: &[String]) -> Result<Vec<Token>, InputError> {
+        let mut tokens = vec![];
+        for (i, line) in lines.iter().enumerate() {
+            let code = line.split('#').next().unwrap_or_default();
+            let chars: Vec<(usize, char)> = code.char_indices().collect();
+            let mut j = 0;
+            while j < chars.len() {
+                let (start, c) = chars[j];
+                let column = j + 1;
+                let mut end = j + 1;
+                let kind = match c {
+                    c if c.is_whitespace() || c == ';' => {
+                        j += 1;
+                        continue;
+                    },
+                    '{' => TokenKind::Open,
+                    '}' => TokenKind::Close,
+                    c if c.is_ascii_alphabetic() => {
+                        while end < chars.len() && chars[end].1.is_ascii_alphabetic() {
+                            end += 1;
+                        }
+                        TokenKind::Word
+                    },
+                    c if c.is_ascii_digit() || c == '-' || c == '+' => {
+                        while end < chars.len() && chars[end].1.is_ascii_digit() {
+                            end += 1;
+                        }
+                        TokenKind::Number
+                    },
+                    c => return Err(Self::error(origin, i + 1, column, &c.to_string(), "Unexpected character")),
+                };
+                let stop = chars.get(end).map(|(offset, _)| *offset).unwrap_or(code.len());
+                tokens.push(Token { kind, text: code[start..stop].to_string(), line: i + 1, column });
+                j = end;
+            }
         }
-    }).reduce(|accumulator, item| (accumulator.0 + item.0, accumulator.1 + item.1)).expect("Input shouldn't be empty.");
-    return total_forward * total_down;
-}
-
-fn solve_problem_02b(input: Vec<String>) -> i32 {
-    let commands = SubmarineCommand::parse_commands(input).unwrap();
-    let (_final_aim, final_forward, final_down): (i32, i32, i32) = commands.iter()
-        .fold((0, 0, 0), |state, item| {
-            let (delta_aim, delta_forward, delta_down): (i32, i32, i32) = match item {
-                SubmarineCommand::Forward(n) => (0, *n, state.0 * n),
-                SubmarineCommand::Down(n) => (*n, 0, 0),
-                SubmarineCommand::Up(n) => (-n, 0, 0),
+        Ok(tokens)
+    }
+
+    fn parse_block(origin: &str, lines: &[String], tokens: &[Token], position: &mut usize, opened_by: Option<&Token>, depth: usize) -> Result<Vec<CourseStatement>, InputError> {
+        let mut statements = vec![];
+        loop {
+            let token = match tokens.get(*position) {
+                Some(token) => token,
+                None => return match opened_by {
+                    Some(repeat) => Err(Self::error(origin, repeat.line, repeat.column, &repeat.text, "Unclosed repeat block")),
+                    None => Ok(statements),
+                },
+            };
+            *position += 1;
+            match (&token.kind, token.text.as_str()) {
+                (TokenKind::Close, _) if opened_by.is_some() => return Ok(statements),
+                (TokenKind::Word, "repeat") => {
+                    if depth == MAX_REPEAT_DEPTH {
+                        return Err(Self::error(origin, token.line, token.column, &token.text, &format!("Repeat blocks cannot nest more than {} deep", MAX_REPEAT_DEPTH)));
+                    }
+                    let count = Self::expect(origin, lines, tokens, position, TokenKind::Number, "Expected a repeat count")?;
+                    let count = count.text.parse::<u32>()
+                        .map_err(|_| Self::error(origin, count.line, count.column, &count.text, "Repeat counts must be non-negative integers"))?;
+                    Self::expect(origin, lines, tokens, position, TokenKind::Open, "Expected '{' after the repeat count")?;
+                    let body = Self::parse_block(origin, lines, tokens, position, Some(token), depth + 1)?;
+                    statements.push(CourseStatement::Repeat(count, body));
+                },
+                (TokenKind::Word, word @ ("forward" | "up" | "down" | "back")) => {
+                    let value = Self::expect(origin, lines, tokens, position, TokenKind::Number, "Expected a value")?;
+                    let command = format!("{} {}", word, value.text).parse::<SubmarineCommand>()
+                        .map_err(|_| Self::error(origin, value.line, value.column, &value.text, "Invalid value"))?;
+                    statements.push(CourseStatement::Command(command));
+                },
+                (TokenKind::Word, word) => return Err(Self::error(origin, token.line, token.column, word, "Unknown command")),
+                _ => return Err(Self::error(origin, token.line, token.column, &token.text, "Expected a command")),
+            }
+        }
+    }
+
+    fn expect<'t>(origin: &str, lines: &[String], tokens: &'t [Token], position: &mut usize, kind: TokenKind, reason: &str) -> Result<&'t Token, InputError> {
+        match tokens.get(*position) {
+            Some(token) if token.kind == kind => {
+                *position += 1;
+                Ok(token)
+            },
+            Some(token) => Err(Self::error(origin, token.line, token.column, &token.text, reason)),
+            None => {
+                let line = lines.len().max(1);
+                let column = lines.last().map(|last| last.chars().count() + 1).unwrap_or(1);
+                Err(Self::error(origin, line, column, "", &format!("{} before the end of the script", reason)))
+            },
+        }
+    }
+
+    fn error(origin: &str, line: usize, column: usize, text: &str, reason: &str) -> InputError {
+        InputError::Parse { file: origin.to_string(), line, column, text: text.to_string(), reason: reason.to_string() }
+    }
+}
+
+struct Frame<'a> {
+    statements: &'a [CourseStatement],
+    index: usize,
+    remaining: u32,
+}
+
+pub struct CourseCommands<'a> {
+    stack: Vec<Frame<'a>>,
+}
+
+impl Iterator for CourseCommands<'_> {
+    type Item = SubmarineCommand;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let frame = self.stack.last_mut()?;
+            let statement = match frame.statements.get(frame.index) {
+                Some(statement) => statement,
+                None if frame.remaining > 1 => {
+                    frame.remaining -= 1;
+                    frame.index = 0;
+                    continue;
+                },
+                None => {
+                    self.stack.pop();
+                    continue;
+                },
             };
-            let (new_aim, new_forward, new_down): (i32, i32, i32) = (state.0 + delta_aim, state.1 + delta_forward, state.2 + delta_down);
-            (new_aim, new_forward, new_down)
+            frame.index += 1;
+            match statement {
+                CourseStatement::Command(command) => return Some(*command),
+                CourseStatement::Repeat(count, body) if *count > 0 && yields_commands(body) => {
+                    self.stack.push(Frame { statements: body, index: 0, remaining: *count });
+                },
+                CourseStatement::Repeat(_, _) => {},
+            }
+        }
+    }
+}
+
+fn yields_commands(statements: &[CourseStatement]) -> bool {
+    statements.iter().any(|statement| match statement {
+        CourseStatement::Command(_) => true,
+        CourseStatement::Repeat(count, body) => *count > 0 && yields_commands(body),
+    })
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Submarine {
+    pub position: i64,
+    pub depth: i64,
+    pub aim: i64,
+}
+
+impl Submarine {
+
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn navigate<I>(self, model: &dyn NavigationModel, commands: I) -> Self
+    where
+        I: IntoIterator<Item = SubmarineCommand>,
+    {
+        commands.into_iter().fold(self, |submarine, command| model.apply(submarine, command))
+    }
+
+    pub fn trace<I>(self, model: &dyn NavigationModel, commands: I) -> CourseTrace
+    where
+        I: IntoIterator<Item = SubmarineCommand>,
+    {
+        let mut states = vec![self];
+        for command in commands {
+            states.push(model.apply(states[states.len() - 1], command));
+        }
+        CourseTrace { states }
+    }
+
+    pub fn product(&self) -> i64 {
+        self.position * self.depth
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CourseTrace {
+    pub states: Vec<Submarine>,
+}
+
+impl CourseTrace {
+
+    pub fn last(&self) -> Submarine {
+        self.states[self.states.len() - 1]
+    }
+
+    fn bounds(&self) -> ((i64, i64), (i64, i64)) {
+        let positions = self.states.iter().map(|state| state.position);
+        let depths = self.states.iter().map(|state| state.depth);
+        (
+            (positions.clone().min().unwrap_or(0), positions.max().unwrap_or(0)),
+            (depths.clone().min().unwrap_or(0), depths.max().unwrap_or(0)),
+        )
+    }
+
+    pub fn render_ascii(&self, width: usize, height: usize) -> String {
+        assert!(width > 1 && height > 1, "Charts need at least 2 columns and 2 rows.");
+        let ((min_position, max_position), (min_depth, max_depth)) = self.bounds();
+        let scale = |value: i64, min: i64, max: i64, cells: usize| match max - min {
+            0 => 0.0,
+            range => (value - min) as f64 * (cells - 1) as f64 / range as f64,
+        };
+        let points: Vec<(f64, f64)> = self.states.iter()
+            .map(|state| (scale(state.position, min_position, max_position, width), scale(state.depth, min_depth, max_depth, height)))
+            .collect();
+
+        let mut cells = vec![vec![' '; width]; height];
+        for pair in points.windows(2) {
+            let ((x0, y0), (x1, y1)) = (pair[0], pair[1]);
+            let steps = (x1 - x0).abs().max((y1 - y0).abs()).ceil().max(1.0) as usize;
+            for step in 0..=steps {
+                let t = step as f64 / steps as f64;
+                cells[(y0 + (y1 - y0) * t).round() as usize][(x0 + (x1 - x0) * t).round() as usize] = '*';
+            }
+        }
+        if let Some((x, y)) = points.first() {
+            cells[y.round() as usize][x.round() as usize] = 'S';
+        }
+        if let Some((x, y)) = points.last().filter(|_| points.len() > 1) {
+            cells[y.round() as usize][x.round() as usize] = 'E';
+        }
+
+        let label_width = min_depth.to_string().len().max(max_depth.to_string().len());
+        let mut chart = String::new();
+        for (row, line) in cells.iter().enumerate() {
+            let label = match row {
+                0 => min_depth.to_string(),
+                _ if row == height - 1 => max_depth.to_string(),
+                _ => String::new(),
+            };
+            let _ = writeln!(chart, "{:>label_width$} |{}", label, line.iter().collect::<String>().trim_end(), label_width = label_width);
+        }
+        let _ = writeln!(chart, "{:>label_width$} +{}", "", "-".repeat(width), label_width = label_width);
+        let max_label = max_position.to_string();
+        let _ = write!(
+            chart,
+            "{:>label_width$}  {}{:>padding$}",
+            "", min_position, max_label,
+            label_width = label_width,
+            padding = width.saturating_sub(min_position.to_string().len()),
+        );
+        chart
+    }
+
+    pub fn render_svg(&self, width: usize, height: usize) -> String {
+        let ((min_position, max_position), (min_depth, max_depth)) = self.bounds();
+        let scale = |value: i64, min: i64, max: i64, size: usize| match max - min {
+            0 => 0.0,
+            range => (value - min) as f64 * size as f64 / range as f64,
+        };
+        let points: Vec<String> = self.states.iter()
+            .map(|state| format!(
+                "{:.1},{:.1}",
+                scale(state.position, min_position, max_position, width),
+                scale(state.depth, min_depth, max_depth, height),
+            ))
+            .collect();
+        format!(
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{w}\" height=\"{h}\" viewBox=\"0 0 {w} {h}\">\n  <polyline fill=\"none\" stroke=\"black\" points=\"{points}\"/>\n</svg>\n",
+            w = width, h = height, points = points.join(" "),
+        )
+    }
+}
+
+pub trait NavigationModel {
+
+    fn apply(&self, submarine: Submarine, command: SubmarineCommand) -> Submarine;
+}
+
+pub struct DirectNavigation;
+
+impl NavigationModel for DirectNavigation {
+
+    fn apply(&self, submarine: Submarine, command: SubmarineCommand) -> Submarine {
+        match command {
+            SubmarineCommand::Forward(n) => Submarine { position: submarine.position + n as i64, ..submarine },
+            SubmarineCommand::Down(n) => Submarine { depth: submarine.depth + n as i64, ..submarine },
+            SubmarineCommand::Up(n) => Submarine { depth: submarine.depth - n as i64, ..submarine },
+            SubmarineCommand::Back(n) => Submarine { position: submarine.position - n as i64, ..submarine },
         }
-    );
-    return final_forward * final_down;
+    }
+}
+
+pub struct AimNavigation;
+
+impl NavigationModel for AimNavigation {
+
+    fn apply(&self, submarine: Submarine, command: SubmarineCommand) -> Submarine {
+        match command {
+            SubmarineCommand::Forward(n) => Submarine {
+                position: submarine.position + n as i64,
+                depth: submarine.depth + submarine.aim * n as i64,
+                ..submarine
+            },
+            SubmarineCommand::Down(n) => Submarine { aim: submarine.aim + n as i64, ..submarine },
+            SubmarineCommand::Up(n) => Submarine { aim: submarine.aim - n as i64, ..submarine },
+            SubmarineCommand::Back(n) => Submarine {
+                position: submarine.position - n as i64,
+                depth: submarine.depth - submarine.aim * n as i64,
+                ..submarine
+            },
+        }
+    }
+}
+
+pub fn solve_problem_02a(commands: Vec<SubmarineCommand>) -> i64 {
+    Submarine::new().navigate(&DirectNavigation, commands).product()
+}
+
+pub fn solve_problem_02b(commands: Vec<SubmarineCommand>) -> i64 {
+    Submarine::new().navigate(&AimNavigation, commands).product()
+}
+
+pub struct Problem02;
+
+impl Solution for Problem02 {
+
+    fn day(&self) -> u32 {
+        2
+    }
+
+    fn input_kind(&self) -> InputKind {
+        InputKind::Lines
+    }
+
+    fn part_a(&self) -> Option<Solver> {
+        Some(|input| solve_problem_02a(input.into_parsed_lines()).into())
+    }
+
+    fn part_b(&self) -> Option<Solver> {
+        Some(|input| solve_problem_02b(input.into_parsed_lines()).into())
+    }
 }
 
 #[cfg(test)]
@@ -62,39 +420,137 @@ mod test_problem_02 {
 
     use super::*;
 
+    fn example_commands() -> Vec<SubmarineCommand> {
+        InputParser::new().parse_lines(Source::text("forward 5\ndown 5\nforward 8\nup 3\ndown 8\nforward 2")).unwrap()
+    }
+
     #[test]
-    fn test_problem_02a_passes() {
-        
-        let input = InputParser::new().parse_as_string("input_02.txt").unwrap();
-        let shortened_input = input.iter().take(15).map(|s| s.to_string()).collect::<Vec<String>>();
+    fn test_navigation_models_return_full_state() {
+        let direct = Submarine::new().navigate(&DirectNavigation, example_commands());
+        assert_eq!(direct, Submarine { position: 15, depth: 10, aim: 0 });
+        assert_eq!(direct.product(), 150);
 
-        assert_eq!(solve_problem_02a(shortened_input), 23*31);
-        assert_eq!(solve_problem_02a(input), 1989265);
+        let aimed = Submarine::new().navigate(&AimNavigation, example_commands());
+        assert_eq!(aimed, Submarine { position: 15, depth: 60, aim: 10 });
+        assert_eq!(aimed.product(), 900);
     }
 
     #[test]
-    fn test_problem_02b_passes() {
-        
-        let input = InputParser::new().parse_as_string("input_02.txt").unwrap();
-        let shortened_input = input.iter().take(15).map(|s| s.to_string()).collect::<Vec<String>>();
-
-        // forward 9 => Aim = 0 => (9, 0)
-        // forward 9 => Aim = 0 => (18, 0)
-        // forward 3 => Aim = 0 => (21, 0)
-        // down 2 => Aim = 2 => (21, 0)
-        // forward 8 => Aim 2 = => (29, 16)
-        // down 8 => Aim = 10 => (29, 16)
-        // forward 1 => Aim = 10 => (30, 26)
-        // down 6 => Aim 16 = => (30, 26)
-        // down 9 => Aim 25 = => (30, 26)
-        // down 9 => Aim 34 = => (30, 26)
-        // forward 1 => Aim 34 = => (31, 60)
-        // up 5 => Aim = 29 => (31, 60)
-        // up 4 => Aim = 25 => (31, 60)
-        // up 8 => Aim = 17 => (31, 60)
-        // down 6 => Aim = 23 = => (31, 60)
-        
-        assert_eq!(solve_problem_02b(shortened_input), 31*60);
-        assert_eq!(solve_problem_02b(input), 2089174012);
-    }
-}
\ No newline at end of file
+    fn test_custom_navigation_model() {
+        struct InvertedControls;
+
+        impl NavigationModel for InvertedControls {
+
+            fn apply(&self, submarine: Submarine, command: SubmarineCommand) -> Submarine {
+                let inverted = match command {
+                    SubmarineCommand::Up(n) => SubmarineCommand::Down(n),
+                    SubmarineCommand::Down(n) => SubmarineCommand::Up(n),
+                    forward => forward,
+                };
+                DirectNavigation.apply(submarine, inverted)
+            }
+        }
+
+        let start = Submarine { position: 1, depth: 100, aim: 0 };
+        assert_eq!(start.navigate(&InvertedControls, example_commands()), Submarine { position: 16, depth: 90, aim: 0 });
+    }
+
+    #[test]
+    fn test_trace_records_every_state() {
+        let trace = Submarine::new().trace(&AimNavigation, example_commands());
+
+        assert_eq!(trace.states.len(), 7);
+        assert_eq!(trace.states[0], Submarine::new());
+        assert_eq!(trace.states[2], Submarine { position: 5, depth: 0, aim: 5 });
+        assert_eq!(trace.last(), Submarine::new().navigate(&AimNavigation, example_commands()));
+    }
+
+    #[test]
+    fn test_trace_renders_ascii_chart() {
+        let trace = Submarine::new().trace(&DirectNavigation, example_commands());
+
+        assert_eq!(trace.render_ascii(16, 3), [
+            " 0 |S*****       *",
+            "   |     *********",
+            "10 |             **E",
+            "   +----------------",
+            "    0             15",
+        ].join("\n"));
+    }
+
+    #[test]
+    fn test_trace_renders_svg_polyline() {
+        let trace = Submarine::new().trace(&DirectNavigation, example_commands());
+        let svg = trace.render_svg(150, 100);
+
+        assert!(svg.starts_with("<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"150\" height=\"100\""));
+        assert!(svg.contains("points=\"0.0,0.0 50.0,0.0 50.0,50.0 130.0,50.0 130.0,20.0 130.0,100.0 150.0,100.0\""), "{}", svg);
+    }
+
+    fn script(text: &str) -> Result<CourseScript, InputError> {
+        CourseScript::load(&InputParser::new(), Source::text(text))
+    }
+
+    #[test]
+    fn test_course_script_expands_repeats_comments_and_back() {
+        let course = script("# survey pattern\nforward 5\nrepeat 2 {\n  down 3 # dive\n  repeat 2 { forward 1; back 1 }\n}\nup -2\n").unwrap();
+
+        assert_eq!(course.commands().collect::<Vec<_>>(), vec![
+            SubmarineCommand::Forward(5),
+            SubmarineCommand::Down(3), SubmarineCommand::Forward(1), SubmarineCommand::Back(1), SubmarineCommand::Forward(1), SubmarineCommand::Back(1),
+            SubmarineCommand::Down(3), SubmarineCommand::Forward(1), SubmarineCommand::Back(1), SubmarineCommand::Forward(1), SubmarineCommand::Back(1),
+            SubmarineCommand::Up(-2),
+        ]);
+        assert_eq!(Submarine::new().navigate(&DirectNavigation, course.commands()), Submarine { position: 5, depth: 8, aim: 0 });
+        assert_eq!(Submarine::new().navigate(&AimNavigation, course.commands()), Submarine { position: 5, depth: 0, aim: 8 });
+        assert!(script("repeat 0 { forward 9 }").unwrap().commands().next().is_none());
+    }
+
+    #[test]
+    fn test_course_script_expands_lazily() {
+        let course = script("repeat 4000000000 { repeat 4000000000 { forward 1 } }").unwrap();
+        assert_eq!(course.commands().take(3).collect::<Vec<_>>(), vec![SubmarineCommand::Forward(1); 3]);
+
+        let course = script("repeat 4000000000 { repeat 4000000000 { repeat 0 { up 1 } } }
+repeat 3 { repeat 2 { } down 1 }").unwrap();
+        assert_eq!(course.commands().collect::<Vec<_>>(), vec![SubmarineCommand::Down(1); 3]);
+    }
+
+    #[test]
+    fn test_course_script_limits_nesting() {
+        let nested = |depth: usize| format!("{}forward 1{}", "repeat 1 { ".repeat(depth), " }".repeat(depth));
+
+        assert_eq!(script(&nested(MAX_REPEAT_DEPTH)).unwrap().commands().count(), 1);
+        let error = script(&nested(MAX_REPEAT_DEPTH + 1)).unwrap_err().to_string();
+        assert!(error.contains("Repeat blocks cannot nest more than 64 deep"), "{}", error);
+    }
+
+    #[test]
+    fn test_course_script_reports_line_and_column() {
+        let position = |text: &str| match script(text).unwrap_err() {
+            InputError::Parse { line, column, text, reason, .. } => (line, column, text, reason),
+            other => panic!("Expected a parse error, got {:?}.", other),
+        };
+
+        assert_eq!(position("forward 5\n  sideways 2"), (2, 3, "sideways".to_string(), "Unknown command".to_string()));
+        assert_eq!(position("down 1\nup x"), (2, 4, "x".to_string(), "Expected a value".to_string()));
+        assert_eq!(position("forward 99999999999"), (1, 9, "99999999999".to_string(), "Invalid value".to_string()));
+        assert_eq!(position("repeat -2 { up 1 }"), (1, 8, "-2".to_string(), "Repeat counts must be non-negative integers".to_string()));
+        assert_eq!(position("forward 1\n  repeat 3 {\n up 1"), (2, 3, "repeat".to_string(), "Unclosed repeat block".to_string()));
+        assert_eq!(position("forward 1 }"), (1, 11, "}".to_string(), "Expected a command".to_string()));
+        assert_eq!(position("down 2 @"), (1, 8, "@".to_string(), "Unexpected character".to_string()));
+        assert_eq!(position("repeat 2\nforward 1"), (2, 1, "forward".to_string(), "Expected '{' after the repeat count".to_string()));
+
+        let error = script("forward").unwrap_err().to_string();
+        assert!(error.ends_with("1:8: Expected a value before the end of the script in \"\"."), "{}", error);
+    }
+
+    #[test]
+    fn test_commands_parse_through_input_parser() {
+        let commands = InputParser::new().parse_lines::<SubmarineCommand, _>("input_02.txt").unwrap();
+        assert_eq!(commands.len(), 1000);
+
+        let error = InputParser::new().parse_lines::<SubmarineCommand, _>(Source::text("forward 5\nsideways 2\nup x")).unwrap_err();
+        assert!(matches!(error, InputError::Multiple(ref errors) if errors.len() == 2));
+    }
+}
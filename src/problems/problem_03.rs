@@ -1,11 +1,12 @@
-use crate::input::input::InputParser;
+use crate::input::input::BinaryInput;
+use crate::problems::solution::{InputKind, Solution, Solver};
 
-fn solve_problem_03a(input: Vec<u32>) -> u32 {
+pub fn solve_problem_03a(input: BinaryInput) -> u64 {
     let mut gamma = 0;
     let mut epsilon = 0;
-    for i in 0..12 {
+    for i in 0..input.width {
         let xor_mask = 1 << i;
-        let (zeros, ones) = count_bits_at_position(&input, i);
+        let (zeros, ones) = count_bits_at_position(&input.values, i);
         (gamma, epsilon) = if zeros < ones {
             (gamma | xor_mask, epsilon)
         } else if zeros > ones {
@@ -18,10 +19,10 @@ fn solve_problem_03a(input: Vec<u32>) -> u32 {
 }
 
 fn recursively_filter_on_criterion_from_the_left(
-    input: Vec<u32>,
-    criterion: fn(&Vec<u32>, usize) -> u32,
+    input: Vec<u64>,
+    criterion: fn(&Vec<u64>, usize) -> u64,
     starting_point: usize
-) -> u32 {
+) -> u64 {
     let mut remaining = input.clone();
     let mut i = starting_point;
     loop { 
@@ -37,7 +38,7 @@ fn recursively_filter_on_criterion_from_the_left(
     return remaining[0];
 }
 
-fn get_most_common_bit_at_position(input: &Vec<u32>, i: usize) -> u32 {
+fn get_most_common_bit_at_position(input: &Vec<u64>, i: usize) -> u64 {
     let xor_mask = 1 << i;
     let (zeros, ones) = count_bits_at_position(&input, i);
     if zeros <= ones {
@@ -47,7 +48,7 @@ fn get_most_common_bit_at_position(input: &Vec<u32>, i: usize) -> u32 {
     }
 }
 
-fn get_least_common_bit_at_position(input: &Vec<u32>, i: usize) -> u32 {
+fn get_least_common_bit_at_position(input: &Vec<u64>, i: usize) -> u64 {
     let xor_mask = 1 << i;
     let (zeros, ones) = count_bits_at_position(&input, i);
     if zeros > ones {
@@ -57,7 +58,7 @@ fn get_least_common_bit_at_position(input: &Vec<u32>, i: usize) -> u32 {
     }
 }
 
-fn count_bits_at_position(input: &Vec<u32>, i: usize) -> (u32, u32) {
+fn count_bits_at_position(input: &Vec<u64>, i: usize) -> (u32, u32) {
     let xor_mask = 1 << i;
     input.iter().fold((0, 0), |state, item| {
         if item & xor_mask == 0 {
@@ -68,59 +69,57 @@ fn count_bits_at_position(input: &Vec<u32>, i: usize) -> (u32, u32) {
     })
 }
 
-fn solve_problem_03b(input: Vec<u32>) -> u32 {
-    let oxygen = recursively_filter_on_criterion_from_the_left(input.clone(), get_most_common_bit_at_position, 11);
-    let co2 = recursively_filter_on_criterion_from_the_left(input.clone(), get_least_common_bit_at_position, 11);
+pub fn solve_problem_03b(input: BinaryInput) -> u64 {
+    let starting_point = input.width - 1;
+    let oxygen = recursively_filter_on_criterion_from_the_left(input.values.clone(), get_most_common_bit_at_position, starting_point);
+    let co2 = recursively_filter_on_criterion_from_the_left(input.values, get_least_common_bit_at_position, starting_point);
 
     return oxygen * co2;
 }
 
-#[cfg(test)]
-mod test_problem_03 {
+pub struct Problem03;
 
-    use super::*;
+impl Solution for Problem03 {
 
-    #[test]
-    fn test_problem_03a_passes() {
-        
-        let input = InputParser::new().parse_as_binary("input_03.txt").unwrap();
-        let shortened_input = input.iter().take(3).map(|s| *s).collect::<Vec<u32>>();
+    fn day(&self) -> u32 {
+        3
+    }
 
-        // 111100101100
-        // 101100110001
-        // 100110100101
-        
-        // => (0, 3), (2, 1), (1, 2), (0, 3), (2, 1), (3, 0), (0, 3), (2, 1), (2, 1), (1, 2), (3, 0), (1, 2)
-        // => Gamma = 101100100101
-        // => Epsilon = 010011011010
-        assert_eq!(solve_problem_03a(shortened_input), 0b101100100101 * 0b010011011010);
+    fn input_kind(&self) -> InputKind {
+        InputKind::Binary
+    }
 
-        assert_eq!(solve_problem_03a(input), 845186);
+    fn part_a(&self) -> Option<Solver> {
+        Some(|input| solve_problem_03a(input.into_binary()).into())
     }
 
-    #[test]
-    fn test_problem_03b_passes() {
-        
-        let input = InputParser::new().parse_as_binary("input_03.txt").unwrap();
-        let shortened_input = input.iter().take(4).map(|s| *s).collect::<Vec<u32>>();
+    fn part_b(&self) -> Option<Solver> {
+        Some(|input| solve_problem_03b(input.into_binary()).into())
+    }
+}
 
-        // 111100101100
-        // 101100110001
-        // 100110100101
-        // 001101100010
+#[cfg(test)]
+mod test_problem_03 {
 
-        // => Oxygen = 101100110001
-        // => CO2 = 001101100010
-    
-        assert_eq!(solve_problem_03b(shortened_input), 0b101100110001 * 0b001101100010);
-        assert_eq!(solve_problem_03b(input), 0);
+    use super::*;
+    use crate::input::input::InputParser;
+    use crate::input::source::Source;
+
+    #[test]
+    fn test_problem_03_passes_on_five_bit_example() {
+        let example = "00100\n11110\n10110\n10111\n10101\n01111\n00111\n11100\n10000\n11001\n00010\n01010";
+        let input = InputParser::new().parse_as_binary(Source::text(example)).unwrap();
+
+        assert_eq!(input.width, 5);
+        assert_eq!(solve_problem_03a(input.clone()), 198);
+        assert_eq!(solve_problem_03b(input), 230);
     }
 
     #[test]
     fn test_get_most_and_least_common_bit() {
         
         let input = InputParser::new().parse_as_binary("input_03.txt").unwrap();
-        let shortened_input = input.iter().take(4).map(|s| *s).collect::<Vec<u32>>();
+        let shortened_input = input.values.iter().take(4).map(|s| *s).collect::<Vec<u64>>();
 
         // 111100101100
         // 101100110001
@@ -1,55 +1,40 @@
 use std::collections::HashSet;
 
 use crate::input::input::InputParser;
+use crate::problems::solution::{InputKind, Solution, Solver};
 
-fn parse_boards(input: Vec<String>) -> Vec<BingoBoard> {
+fn parse_game(input: Vec<String>) -> (Vec<u32>, Vec<BingoBoard>) {
+    let mut blocks = InputParser::blocks(input).into_iter();
+    let guesses = blocks.next().expect("Input should start with the guesses.")
+        .join(",").split(',').map(|guess| guess.trim().parse::<u32>().unwrap()).collect();
+    let boards = blocks.map(BingoBoard::from_row_strings).collect();
 
-    let board_row_strings: Vec<Vec<String>> = InputParser::chunk(
-        input[1..].into_iter().map(|x| x.to_string()).collect(),
-        6
-    ).unwrap().into_iter().map(
-        |chunk| chunk.into_iter().skip(1).take(5).collect()
-    ).collect();
-
-    let mut boards = Vec::new();
-    for row_strings in board_row_strings {
-        let board = BingoBoard::from_row_strings(row_strings);
-        boards.push(board);
-    }
-
-    return boards;
+    return (guesses, boards);
 }
 
 pub fn solve_problem_04a(input: Vec<String>) -> u32 {
-    let guesses = &input[0];
-    let mut boards = parse_boards(input.clone());
+    let (guesses, mut boards) = parse_game(input);
 
-    for guess in guesses.split(",") {
-        let guess_value = guess.parse::<u32>().unwrap();
-        let mut board_n = 0;
+    for guess_value in guesses {
         for board in &mut boards {
             board.mark(guess_value);
             if board.get_bingo_sum() > 0 {
                 let unmarked_number_sum = board.get_unmarked_number_sum();
-                println!("Bingo on board {} after guess {}!", board_n, guess_value);
                 let answer = unmarked_number_sum * guess_value;
                 return answer;
             }
-            board_n += 1;
         }
     }
     panic!("Reached the end of the file without bingo!");
 }
 
-fn solve_problem_04b(input: Vec<String>) -> u32 {
+pub fn solve_problem_04b(input: Vec<String>) -> u32 {
 
-    let guesses = &input[0];
-    let mut boards = parse_boards(input.clone());
+    let (guesses, mut boards) = parse_game(input);
     let mut last_winning_board = None;
     let mut last_winning_guess = None;
     let mut already_won: HashSet<u32> = HashSet::new();
-    for guess in guesses.split(",") {
-        let guess_value = guess.parse::<u32>().unwrap();
+    for guess_value in guesses {
         let mut board_n = 0;
         for board in &mut boards {
             board.mark(guess_value);
@@ -64,13 +49,13 @@ fn solve_problem_04b(input: Vec<String>) -> u32 {
     let last_winning_board = last_winning_board.unwrap();
     let last_winning_guess = last_winning_guess.unwrap();
     let unmarked_number_sum = last_winning_board.get_unmarked_number_sum();
-    println!("Last bingo on board {:?} after guess {}!", last_winning_board, last_winning_guess);
     let answer = unmarked_number_sum * last_winning_guess;
     return answer;
 }
 
 #[derive(Debug, Clone)]
 struct BingoBoard {
+    size: usize,
     rows: Vec<Vec<u32>>,
     marked: Vec<Vec<bool>>,
 }
@@ -78,9 +63,10 @@ struct BingoBoard {
 impl BingoBoard {
 
     pub fn new(rows: Vec<Vec<u32>>) -> Self {
-        assert_eq!(rows.len(), 5);
-        assert!(rows.iter().all(|r| r.len() == 5));
-        Self { rows, marked: vec![vec![false; 5]; 5] }
+        let size = rows.len();
+        assert!(size > 0);
+        assert!(rows.iter().all(|r| r.len() == size));
+        Self { size, rows, marked: vec![vec![false; size]; size] }
     }
 
     pub fn from_row_strings(row_strings: Vec<String>) -> Self {
@@ -100,16 +86,8 @@ impl BingoBoard {
         }
     }
 
-    pub fn is_marked(&self, row_index: usize, column_index: usize) -> bool {
-        self.marked[row_index][column_index]
-    }
-
-    pub fn clear(&mut self) {
-        self.marked = vec![vec![false; 5]; 5];
-    }
-
     pub fn columns(&self) -> Vec<Vec<u32>> {
-        let mut columns = vec![vec![]; 5];
+        let mut columns = vec![vec![]; self.size];
         for row in self.rows.iter() {
             for (column_index, cell) in row.iter().enumerate() {
                 columns[column_index].push(*cell);
@@ -119,7 +97,7 @@ impl BingoBoard {
     }
 
     pub fn marked_columns(&self) -> Vec<Vec<bool>> {
-        let mut marked_columns = vec![vec![]; 5];
+        let mut marked_columns = vec![vec![]; self.size];
         for row in self.marked.iter() {
             for (column_index, cell) in row.iter().enumerate() {
                 marked_columns[column_index].push(*cell);
@@ -128,24 +106,6 @@ impl BingoBoard {
         marked_columns
     }
 
-    pub fn diagonals(&self) -> Vec<Vec<u32>> {
-        let mut diagonals = vec![vec![]; 2];
-        for (row_index, row) in self.rows.iter().enumerate() {
-            diagonals[0].push(row[row_index]);
-            diagonals[1].push(row[4 - row_index]);
-        }
-        diagonals
-    }
-
-    pub fn marked_diagonals(&self) -> Vec<Vec<bool>> {
-        let mut marked_diagonals = vec![vec![]; 2];
-        for (row_index, row) in self.marked.iter().enumerate() {
-            marked_diagonals[0].push(row[row_index]);
-            marked_diagonals[1].push(row[4 - row_index]);
-        }
-        marked_diagonals
-    }
-
     pub fn sum(&self) -> u32 {
         self.rows.iter().flatten().sum()
     }
@@ -181,49 +141,62 @@ impl BingoBoard {
             }
         }).sum()
     }
+}
 
-    pub fn get_bingo_sum_diagonal(&self) -> u32 {
-        self.marked_diagonals().iter().enumerate().map(|diagonal| {
-            if diagonal.1.iter().all(|x| *x) {
-                self.diagonals()[diagonal.0].iter().sum()
-            } else {
-                0
-            }
-        }).sum()
+pub struct Problem04;
+
+impl Solution for Problem04 {
+
+    fn day(&self) -> u32 {
+        4
     }
-    
-}
 
-enum BingoType {
-    Row(usize),
-    Column(usize)
+    fn input_kind(&self) -> InputKind {
+        InputKind::Lines
+    }
+
+    fn part_a(&self) -> Option<Solver> {
+        Some(|input| solve_problem_04a(input.into_lines()).into())
+    }
+
+    fn part_b(&self) -> Option<Solver> {
+        Some(|input| solve_problem_04b(input.into_lines()).into())
+    }
 }
 
 #[cfg(test)]
 mod test_problem_04 {
 
     use super::*;
+    use crate::input::source::Source;
 
     #[test]
-    fn test_problem_04a_passes() {
-        
-        let input = InputParser::new().parse_as_string("input_04.txt").unwrap();
+    fn test_parse_game_ignores_blank_line_layout() {
+        let input = InputParser::new().parse_as_string(Source::text(
+            "\r\n7,4,9\r\n\r\n\r\n 1  2  3\r\n 4  5  6\r\n 7  8  9\r\n\r\n1 2\r\n3 4\r\n\r\n\r\n"
+        )).unwrap();
+        let (guesses, boards) = parse_game(input);
 
-        let answer = solve_problem_04a(input);
-        assert_eq!(answer, 87456); // 31584 is wrong
+        assert_eq!(guesses, vec![7, 4, 9]);
+        assert_eq!(boards.len(), 2);
+        assert_eq!(boards[0].sum(), 45);
+        assert_eq!(boards[1].columns(), vec![vec![1, 3], vec![2, 4]]);
     }
-    
-    #[test]
-    fn test_problem_04b_passes() {
-        let input = InputParser::new().parse_as_string("input_04.txt").unwrap();
 
-        let answer = solve_problem_04b(input);
-        assert_eq!(answer, 0);
+    #[test]
+    fn test_get_bingo_sum_on_smaller_board() {
+        let mut board = BingoBoard::new(vec![vec![1, 2, 3], vec![4, 5, 6], vec![7, 8, 9]]);
+        board.mark(2);
+        board.mark(5);
+        assert_eq!(board.get_bingo_sum(), 0);
+        board.mark(8);
+        assert_eq!(board.get_bingo_sum(), 15);
+        assert_eq!(board.get_unmarked_number_sum(), 30);
     }
 
     #[test]
     fn test_get_bingo_sum() {
-        let mut board = BingoBoard::new(
+        let new_board = || BingoBoard::new(
             vec![
                 vec![1, 2, 3, 4, 5],
                 vec![6, 7, 8, 9, 10],
@@ -232,6 +205,7 @@ mod test_problem_04 {
                 vec![21, 22, 23, 24, 25],
             ]
         );
+        let mut board = new_board();
         assert_eq!(board.get_bingo_sum(), 0);
         board.mark(1);
         assert_eq!(board.get_bingo_sum(), 0);
@@ -242,7 +216,7 @@ mod test_problem_04 {
         board.mark(5);
         assert_eq!(board.get_bingo_sum(), 15);
 
-        board.clear();
+        board = new_board();
 
         assert_eq!(board.get_bingo_sum(), 0);
         board.mark(6);
@@ -252,7 +226,7 @@ mod test_problem_04 {
         board.mark(10);
         assert_eq!(board.get_bingo_sum(), 40);
 
-        board.clear();
+        board = new_board();
 
         assert_eq!(board.get_bingo_sum(), 0);
         board.mark(5);
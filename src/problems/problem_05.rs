@@ -1,10 +1,8 @@
-use std::collections::{HashMap, HashSet};
+use std::{collections::{HashMap, HashSet}, str::FromStr};
 
-use crate::input::input::InputParser;
+use crate::problems::solution::{InputKind, Solution, Solver};
 
-fn count_intersections(input: Vec<String>, filter: fn(&LineSegment) -> bool) -> usize {
-    let segments = LineSegment::parse_all(input).unwrap();
-    
+fn count_intersections(segments: Vec<LineSegment>, filter: fn(&LineSegment) -> bool) -> usize {
     let points: Vec<(u32, u32)> = segments.into_iter().filter(filter)
         .map(|x| x.get_points()).flatten().collect();
     
@@ -21,15 +19,16 @@ fn count_intersections(input: Vec<String>, filter: fn(&LineSegment) -> bool) ->
 
 }
 
-fn solve_problem_05a(input: Vec<String>) -> usize {
-    count_intersections(input, |x| x.is_horizontal() || x.is_vertical())
+pub fn solve_problem_05a(segments: Vec<LineSegment>) -> usize {
+    count_intersections(segments, |x| x.is_horizontal() || x.is_vertical())
 }
 
-fn solve_problem_05b(input: Vec<String>) -> usize {
-    count_intersections(input, |x| x.is_horizontal() || x.is_vertical() || x.is_diagonal())
+pub fn solve_problem_05b(segments: Vec<LineSegment>) -> usize {
+    count_intersections(segments, |x| x.is_horizontal() || x.is_vertical() || x.is_diagonal())
 }
 
-struct LineSegment {
+#[derive(Debug)]
+pub struct LineSegment {
     start: (u32, u32),
     end: (u32, u32),
 }
@@ -43,27 +42,11 @@ impl LineSegment {
         }
     }
 
-    pub fn parse_all(input: Vec<String>) -> Result<Vec<Self>, String> {
-        input.iter().map(|line| Self::from_string(line)).collect()
-    }
-
-    pub fn from_string(s: &str) -> Result<Self, String> {
-        let parts: Vec<_> = s.split_whitespace().collect();
-        assert_eq!(parts.len(), 3);
-        let start_string = parts[0];
-        let middle_string = parts[1];
-        let end_string = parts[2];
-
-        assert_eq!(middle_string, "->");
-        let start_parts = Self::parse_part(start_string)?;
-        let end_parts = Self::parse_part(end_string)?;
-
-        Ok(Self::new((start_parts[0], start_parts[1]), (end_parts[0], end_parts[1])))
-    }
-
     pub fn parse_part(s: &str) -> Result<Vec<u32>, String> {
         let parts: Vec<_> = s.split(',').collect();
-        assert_eq!(parts.len(), 2);
+        if parts.len() != 2 {
+            return Err(format!("Expected a point like x,y but found {}.", s));
+        }
         let x = parts[0].parse::<u32>().map_err(|_| "Unable to parse part to u32")?;
         let y = parts[1].parse::<u32>().map_err(|_| "Unable to parse part to u32")?;
         Ok(vec![x, y])
@@ -125,32 +108,60 @@ impl LineSegment {
     }
 
 }
-#[cfg(test)]
-mod test_problem_05 {
 
-    use super::*;
+impl FromStr for LineSegment {
+    type Err = String;
 
-    #[test]
-    fn test_problem_05a_passes() {
-        
-        let input = InputParser::new().parse_as_string("input_05.txt").unwrap();
-        let shorted_input = input.iter().take(10).map(|i| i.clone()).collect();
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let parts: Vec<_> = s.split_whitespace().collect();
+        if parts.len() != 3 || parts[1] != "->" {
+            return Err(format!("Expected a segment like x1,y1 -> x2,y2 but found {}.", s));
+        }
+        let start_parts = Self::parse_part(parts[0])?;
+        let end_parts = Self::parse_part(parts[2])?;
 
-        assert_eq!(solve_problem_05a(shorted_input), 0);
+        Ok(Self::new((start_parts[0], start_parts[1]), (end_parts[0], end_parts[1])))
+    }
+}
+
+pub struct Problem05;
+
+impl Solution for Problem05 {
 
-        let answer = solve_problem_05a(input);
-        assert_eq!(answer, 4421);
+    fn day(&self) -> u32 {
+        5
     }
-    
+
+    fn input_kind(&self) -> InputKind {
+        InputKind::Lines
+    }
+
+    fn part_a(&self) -> Option<Solver> {
+        Some(|input| solve_problem_05a(input.into_parsed_lines()).into())
+    }
+
+    fn part_b(&self) -> Option<Solver> {
+        Some(|input| solve_problem_05b(input.into_parsed_lines()).into())
+    }
+}
+
+#[cfg(test)]
+mod test_problem_05 {
+
+    use super::*;
+    use crate::input::{error::InputError, input::InputParser, source::Source};
+
     #[test]
-    fn test_problem_05b_passes() {
-        let input = InputParser::new().parse_as_string("input_05.txt").unwrap();
-        let shorted_input = input.iter().take(10).map(|i| i.clone()).collect();
+    fn test_segments_parse_through_input_parser() {
+        let segments = InputParser::new().parse_lines::<LineSegment, _>("input_05.txt").unwrap();
+        assert_eq!(segments.len(), 500);
 
-        assert_eq!(solve_problem_05b(shorted_input), 6);
+        let segments = InputParser::new().parse_lines::<LineSegment, _>(Source::text("0,9 -> 5,9\n8,0 -> 0,8")).unwrap();
+        assert!(segments[0].is_horizontal());
+        assert!(segments[1].is_diagonal());
 
-        let answer = solve_problem_05b(input);
-        assert_eq!(answer, 10157);
+        let error = InputParser::new().parse_lines::<LineSegment, _>(Source::text("0,9 -> 5,9\n8,0 => 0,8")).unwrap_err();
+        assert!(matches!(error, InputError::Parse { line: 2, .. }));
     }
 
     #[test]
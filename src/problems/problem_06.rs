@@ -1,6 +1,6 @@
-use std::{iter, collections::HashMap};
+use std::iter;
 
-use crate::input::input::InputParser;
+use crate::problems::solution::{InputKind, Solution, Solver};
 
 pub fn solve_problem_06a(input: Vec<u32>) -> usize {
     let mut population = Population::new(input);
@@ -10,11 +10,14 @@ pub fn solve_problem_06a(input: Vec<u32>) -> usize {
     return population.len();
 }
 
-fn solve_problem_06b(input: Vec<u32>) -> usize {
-    let mut populations = (0..7).into_iter().map(|n| Population::new(vec![n])).collect::<Vec<_>>();
-    populations.iter_mut().for_each(|population| (0..32).into_iter().for_each(|_n| population.tick()));
-
-    input.into_iter().map(|timer| populations[timer as usize].len()).sum()
+pub fn solve_problem_06b(input: Vec<u32>) -> usize {
+    let mut counts = [0usize; 9];
+    input.into_iter().for_each(|timer| counts[timer as usize] += 1);
+    for _i in 0..256 {
+        counts.rotate_left(1);
+        counts[6] += counts[8];
+    }
+    counts.iter().sum()
 }
 
 struct Population {
@@ -24,7 +27,7 @@ struct Population {
 impl Population {
 
     pub fn new(timers: Vec<u32>) -> Self {
-        Self {timers, cached: HashMap::new()}
+        Self {timers}
     }
 
     pub fn len(&self) -> usize {
@@ -37,17 +40,26 @@ impl Population {
         temp_timers.extend(iter::repeat(8).take(n_new_timers));
         self.timers = temp_timers;
     }
+}
+
+pub struct Problem06;
+
+impl Solution for Problem06 {
+
+    fn day(&self) -> u32 {
+        6
+    }
 
-    pub fn get_timers(&self) -> &Vec<u32> {
-        &self.timers
+    fn input_kind(&self) -> InputKind {
+        InputKind::CommaSeparated
     }
 
-    pub fn tick_for(&mut self, n_generations: u32) {
-        let n_partitions = (n_generations / 32) - 1;
-        let cache_size = n_generations / n_partitions;
-        let mut populations = (0..9).into_iter().map(|n| Population::new(vec![n])).collect::<Vec<_>>();
-        populations.iter_mut().for_each(|population| (0..n_generations).into_iter().for_each(|_n| population.tick()));
-        let cache = populations.into_iter().enumerate().map(|(n, p)| (n, p.get_timers().clone())).collect::<HashMap<_, _>>();
+    fn part_a(&self) -> Option<Solver> {
+        Some(|input| solve_problem_06a(input.into_comma_separated()).into())
+    }
+
+    fn part_b(&self) -> Option<Solver> {
+        Some(|input| solve_problem_06b(input.into_comma_separated()).into())
     }
 }
 
@@ -56,60 +68,18 @@ mod test_problem_06 {
 
     use super::*;
 
-    #[test]
-    fn test_problem_06a_passes() {
-        
-        let shorted_input: Vec<u32> = vec![
-            3,
-            4,
-            3,
-            1,
-            2
-        ];
-
-        assert_eq!(solve_problem_06a(shorted_input), 5934);
-
-        let input = InputParser::new().parse_as_string("input_06.txt").unwrap();
-        assert_eq!(input.len(), 1);
-        let timers: Vec<u32> = input[0].split(",").into_iter().map(|s| s.parse::<u32>().expect("We should be able to parse into a number.")).collect();
-        
-        let answer = solve_problem_06a(timers);
-        assert_eq!(answer, 0);
-    }
-    
-    #[test]
-    fn test_problem_06b_passes() {
-  
-        let shorted_input: Vec<u32> = vec![
-            3,
-            4,
-            3,
-            1,
-            2
-        ];
-
-        assert_eq!(solve_problem_06b(shorted_input), 26984457539);
-
-        // let input = InputParser::new().parse_as_string("input_06.txt").unwrap();
-        // assert_eq!(input.len(), 1);
-        // let timers: Vec<u32> = input[0].split(",").into_iter().map(|s| s.parse::<u32>().expect("We should be able to parse into a number.")).collect();
-        
-        // let answer = solve_problem_06b(timers);
-        // assert_eq!(answer, 0);
-    }
-
     #[test]
     fn test_population_ticks() {
         let mut population = Population::new(vec![1, 2, 3]);
         assert_eq!(population.len(), 3);
-        assert_eq!(population.get_timers(), &vec![1, 2, 3]);
+        assert_eq!(population.timers, vec![1, 2, 3]);
 
         population.tick();
         assert_eq!(population.len(), 3);
-        assert_eq!(population.get_timers(), &vec![0, 1, 2]);
+        assert_eq!(population.timers, vec![0, 1, 2]);
 
         population.tick();
         assert_eq!(population.len(), 4);
-        assert_eq!(population.get_timers(), &vec![6, 0, 1, 8]);
+        assert_eq!(population.timers, vec![6, 0, 1, 8]);
     }
 }
\ No newline at end of file
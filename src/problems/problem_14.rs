@@ -1,39 +1,14 @@
-use crate::input::input::InputParser;
+use crate::problems::solution::{InputKind, Solution};
 
-pub fn solve_problem_14a(input: Vec<String>) -> usize {
-    unimplemented!();
-}
-
-fn solve_problem_14b(input: Vec<String>) -> usize {
-    unimplemented!();
-}
-
-#[cfg(test)]
-mod test_problem_14 {
-
-    use super::*;
+pub struct Problem14;
 
-    #[test]
-    fn test_problem_14a_passes() {
-        
-        let input = InputParser::new().parse_as_string("input_14.txt").unwrap();
-        let shorted_input = input.iter().take(10).map(|i| i.clone()).collect();
+impl Solution for Problem14 {
 
-        assert_eq!(solve_problem_14a(shorted_input), 0);
-
-        let answer = solve_problem_14a(input);
-        assert_eq!(answer, 0);
+    fn day(&self) -> u32 {
+        14
     }
-    
-    #[test]
-    fn test_problem_14b_passes() {
-        let input = InputParser::new().parse_as_string("input_14.txt").unwrap();
-        let shorted_input = input.iter().take(10).map(|i| i.clone()).collect();
-
-        assert_eq!(solve_problem_14b(shorted_input), 0);
 
-        let answer = solve_problem_14b(input);
-        assert_eq!(answer, 0);
+    fn input_kind(&self) -> InputKind {
+        InputKind::Lines
     }
-
-}
\ No newline at end of file
+}
@@ -0,0 +1,169 @@
+use std::{fmt::Display, str::FromStr};
+
+use crate::input::{error::InputError, grid::Grid, input::{BinaryInput, InputParser}, source::Source};
+use crate::problems::answer::Answer;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Part {
+    A,
+    B,
+}
+
+impl Part {
+
+    pub fn both() -> [Self; 2] {
+        [Self::A, Self::B]
+    }
+
+    pub fn parse(s: &str) -> Result<Self, String> {
+        match s.to_lowercase().as_str() {
+            "a" | "1" => Ok(Self::A),
+            "b" | "2" => Ok(Self::B),
+            _ => Err(format!("Invalid part: {}.", s)),
+        }
+    }
+
+    pub fn letter(&self) -> char {
+        match self {
+            Self::A => 'a',
+            Self::B => 'b',
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InputKind {
+    Integers,
+    Binary,
+    Lines,
+    CommaSeparated,
+    Grid,
+    Blocks,
+}
+
+impl InputKind {
+
+    pub fn load<'a, S: Into<Source<'a>>>(&self, parser: &InputParser, source: S) -> Result<Input, InputError> {
+        match self {
+            Self::Integers => parser.parse_as_i32(source).map(Input::Integers),
+            Self::Binary => parser.parse_as_binary(source).map(Input::Binary),
+            Self::Lines => parser.parse_as_string(source).map(Input::Lines),
+            Self::CommaSeparated => parser.parse_comma_separated(source).map(Input::CommaSeparated),
+            Self::Grid => parser.parse_as_grid(source).map(Input::Grid),
+            Self::Blocks => parser.parse_blocks(source).map(Input::Blocks),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Input {
+    Integers(Vec<i32>),
+    Binary(BinaryInput),
+    Lines(Vec<String>),
+    CommaSeparated(Vec<u32>),
+    Grid(Grid<u8>),
+    Blocks(Vec<Vec<String>>),
+}
+
+impl Input {
+
+    pub fn kind(&self) -> InputKind {
+        match self {
+            Self::Integers(_) => InputKind::Integers,
+            Self::Binary(_) => InputKind::Binary,
+            Self::Lines(_) => InputKind::Lines,
+            Self::CommaSeparated(_) => InputKind::CommaSeparated,
+            Self::Grid(_) => InputKind::Grid,
+            Self::Blocks(_) => InputKind::Blocks,
+        }
+    }
+
+    pub fn into_integers(self) -> Vec<i32> {
+        match self {
+            Self::Integers(integers) => integers,
+            other => panic!("Expected integer input but got {:?}.", other.kind()),
+        }
+    }
+
+    pub fn into_binary(self) -> BinaryInput {
+        match self {
+            Self::Binary(values) => values,
+            other => panic!("Expected binary input but got {:?}.", other.kind()),
+        }
+    }
+
+    pub fn into_lines(self) -> Vec<String> {
+        match self {
+            Self::Lines(lines) => lines,
+            other => panic!("Expected line input but got {:?}.", other.kind()),
+        }
+    }
+
+    pub fn into_parsed_lines<T>(self) -> Vec<T>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        InputParser::lines_as("input", &self.into_lines()).unwrap_or_else(|error| panic!("{}", error))
+    }
+
+    pub fn into_comma_separated(self) -> Vec<u32> {
+        match self {
+            Self::CommaSeparated(values) => values,
+            other => panic!("Expected comma-separated input but got {:?}.", other.kind()),
+        }
+    }
+
+    pub fn into_grid(self) -> Grid<u8> {
+        match self {
+            Self::Grid(grid) => grid,
+            other => panic!("Expected grid input but got {:?}.", other.kind()),
+        }
+    }
+
+    pub fn into_blocks(self) -> Vec<Vec<String>> {
+        match self {
+            Self::Blocks(blocks) => blocks,
+            other => panic!("Expected blank-line separated blocks but got {:?}.", other.kind()),
+        }
+    }
+}
+
+pub type Solver = fn(Input) -> Answer;
+
+pub trait Solution {
+
+    fn day(&self) -> u32;
+
+    fn input_kind(&self) -> InputKind;
+
+    fn part_a(&self) -> Option<Solver> {
+        None
+    }
+
+    fn part_b(&self) -> Option<Solver> {
+        None
+    }
+
+    fn input_file(&self) -> String {
+        format!("input_{:02}.txt", self.day())
+    }
+
+    fn solver(&self, part: Part) -> Option<Solver> {
+        match part {
+            Part::A => self.part_a(),
+            Part::B => self.part_b(),
+        }
+    }
+
+    fn implemented(&self, part: Part) -> bool {
+        self.solver(part).is_some()
+    }
+
+    fn solve(&self, part: Part, input: Input) -> Answer {
+        match self.solver(part) {
+            Some(solver) => solver(input),
+            None => unimplemented!("Day {:02}{} has no solver", self.day(), part.letter()),
+        }
+    }
+}
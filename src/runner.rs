@@ -0,0 +1,421 @@
+use std::{any::Any, cell::{Cell, RefCell}, fmt, panic::{self, AssertUnwindSafe}, sync::Once, time::{Duration, Instant}};
+
+use crate::input::{error::InputError, input::InputParser, source::Source};
+use crate::output::{format_duration, json_string};
+use crate::problems::solution::{Input, Part, Solution};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OutputFormat {
+    Human,
+    JsonLines,
+    Csv,
+}
+
+impl OutputFormat {
+
+    pub fn parse(s: &str) -> Result<Self, String> {
+        match s.to_lowercase().as_str() {
+            "human" | "table" => Ok(Self::Human),
+            "json" | "jsonl" | "json-lines" => Ok(Self::JsonLines),
+            "csv" => Ok(Self::Csv),
+            _ => Err(format!("Invalid output format: {}. Expected human, json or csv.", s)),
+        }
+    }
+}
+
+thread_local! {
+    static CATCHING: Cell<bool> = const { Cell::new(false) };
+    static LOCATION: RefCell<Option<String>> = const { RefCell::new(None) };
+}
+
+static INSTALL_HOOK: Once = Once::new();
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Panic {
+    pub message: String,
+    pub location: Option<String>,
+}
+
+impl Panic {
+
+    pub fn is_unimplemented(&self) -> bool {
+        ["not implemented", "not yet implemented"].iter()
+            .any(|prefix| self.message == *prefix || self.message.starts_with(&format!("{}: ", prefix)))
+    }
+}
+
+impl fmt::Display for Panic {
+
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.location {
+            Some(location) => write!(f, "{} at {}", self.message, location),
+            None => write!(f, "{}", self.message),
+        }
+    }
+}
+
+pub fn catch_panic<T, F: FnOnce() -> T>(f: F) -> Result<T, Panic> {
+    INSTALL_HOOK.call_once(|| {
+        let default_hook = panic::take_hook();
+        panic::set_hook(Box::new(move |info| {
+            if CATCHING.with(|catching| catching.get()) {
+                let location = info.location().map(|location| format!("{}:{}:{}", location.file(), location.line(), location.column()));
+                LOCATION.with(|cell| *cell.borrow_mut() = location);
+            } else {
+                default_hook(info);
+            }
+        }));
+    });
+
+    let was_catching = CATCHING.with(|catching| catching.replace(true));
+    LOCATION.with(|cell| cell.borrow_mut().take());
+    let result = panic::catch_unwind(AssertUnwindSafe(f));
+    CATCHING.with(|catching| catching.set(was_catching));
+    result.map_err(|payload| Panic {
+        message: panic_message(payload.as_ref()),
+        location: LOCATION.with(|cell| cell.borrow_mut().take()),
+    })
+}
+
+fn panic_message(payload: &(dyn Any + Send)) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message.to_string()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "Unknown panic payload.".to_string()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RunStatus {
+    Ok,
+    Unimplemented,
+    Error(String),
+    Crashed(Panic),
+}
+
+impl RunStatus {
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Ok => "ok",
+            Self::Unimplemented => "unimplemented",
+            Self::Error(_) => "error",
+            Self::Crashed(_) => "crashed",
+        }
+    }
+
+    pub fn message(&self) -> Option<&str> {
+        match self {
+            Self::Error(message) => Some(message),
+            Self::Crashed(panic) => Some(&panic.message),
+            _ => None,
+        }
+    }
+
+    pub fn location(&self) -> Option<&str> {
+        match self {
+            Self::Crashed(panic) => panic.location.as_deref(),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for RunStatus {
+
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Error(message) => write!(f, "{}: {}", self.name(), message),
+            Self::Crashed(panic) => write!(f, "{}: {}", self.name(), panic),
+            _ => write!(f, "{}", self.name()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RunRecord {
+    pub day: u32,
+    pub part: Part,
+    pub answer: Option<String>,
+    pub duration: Option<Duration>,
+    pub status: RunStatus,
+}
+
+impl RunRecord {
+
+    fn without_answer(day: u32, part: Part, status: RunStatus) -> Self {
+        Self { day, part, answer: None, duration: None, status }
+    }
+}
+
+pub fn run_solution(parser: &InputParser, solution: &dyn Solution, parts: &[Part]) -> Vec<RunRecord> {
+    run_input(solution, solution.input_kind().load(parser, Source::file(&solution.input_file())), parts)
+}
+
+pub fn run_input(solution: &dyn Solution, input: Result<Input, InputError>, parts: &[Part]) -> Vec<RunRecord> {
+    let day = solution.day();
+    parts.iter().map(|part| {
+        let input = match &input {
+            _ if !solution.implemented(*part) => return RunRecord::without_answer(day, *part, RunStatus::Unimplemented),
+            Err(error) => return RunRecord::without_answer(day, *part, RunStatus::Error(error.to_string())),
+            Ok(input) => input.clone(),
+        };
+        let start = Instant::now();
+        match catch_panic(|| solution.solve(*part, input).to_string()) {
+            Ok(answer) => RunRecord { day, part: *part, answer: Some(answer), duration: Some(start.elapsed()), status: RunStatus::Ok },
+            Err(panic) if panic.is_unimplemented() => RunRecord::without_answer(day, *part, RunStatus::Unimplemented),
+            Err(panic) => RunRecord::without_answer(day, *part, RunStatus::Crashed(panic)),
+        }
+    }).collect()
+}
+
+pub fn run_all(parser: &InputParser, solutions: &[Box<dyn Solution>], parts: &[Part]) -> Vec<RunRecord> {
+    solutions.iter().flat_map(|solution| run_solution(parser, solution.as_ref(), parts)).collect()
+}
+
+pub fn format_records(records: &[RunRecord], format: OutputFormat) -> String {
+    match format {
+        OutputFormat::Human => human(records),
+        OutputFormat::JsonLines => json_lines(records),
+        OutputFormat::Csv => csv(records),
+    }
+}
+
+fn human(records: &[RunRecord]) -> String {
+    let mut lines = vec![format!("{:<5} {:<4} {:<13} {:>12}  {}", "Day", "Part", "Status", "Duration", "Answer")];
+    for record in records {
+        lines.push(format!(
+            "{:<5} {:<4} {:<13} {:>12}  {}",
+            format!("{:02}", record.day),
+            record.part.letter(),
+            record.status.name(),
+            record.duration.map(format_duration).unwrap_or_default(),
+            record.answer.clone().or_else(|| detail(&record.status)).unwrap_or_default(),
+        ).trim_end().to_string());
+    }
+    lines.join("\n")
+}
+
+fn detail(status: &RunStatus) -> Option<String> {
+    match status {
+        RunStatus::Crashed(panic) => Some(panic.to_string()),
+        status => status.message().map(|message| message.to_string()),
+    }
+}
+
+fn json_lines(records: &[RunRecord]) -> String {
+    records.iter().map(|record| format!(
+        "{{\"day\":{},\"part\":\"{}\",\"answer\":{},\"duration_ns\":{},\"status\":\"{}\",\"message\":{},\"location\":{}}}",
+        record.day,
+        record.part.letter(),
+        record.answer.as_deref().map(json_string).unwrap_or("null".to_string()),
+        record.duration.map(|duration| duration.as_nanos().to_string()).unwrap_or("null".to_string()),
+        record.status.name(),
+        record.status.message().map(json_string).unwrap_or("null".to_string()),
+        record.status.location().map(json_string).unwrap_or("null".to_string()),
+    )).collect::<Vec<_>>().join("\n")
+}
+
+fn csv(records: &[RunRecord]) -> String {
+    let mut lines = vec!["day,part,answer,duration_ns,status,message,location".to_string()];
+    for record in records {
+        lines.push([
+            record.day.to_string(),
+            record.part.letter().to_string(),
+            csv_field(record.answer.as_deref().unwrap_or_default()),
+            record.duration.map(|duration| duration.as_nanos().to_string()).unwrap_or_default(),
+            record.status.name().to_string(),
+            csv_field(record.status.message().unwrap_or_default()),
+            csv_field(record.status.location().unwrap_or_default()),
+        ].join(","));
+    }
+    lines.join("\n")
+}
+
+fn csv_field(s: &str) -> String {
+    if s.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", s.replace('"', "\"\""))
+    } else {
+        s.to_string()
+    }
+}
+
+#[cfg(test)]
+mod test_runner {
+    use super::*;
+    use crate::problems::{self, answer::Answer, solution::{InputKind, Solver}};
+
+    fn sample_records() -> Vec<RunRecord> {
+        vec![
+            RunRecord { day: 1, part: Part::A, answer: Some("7".to_string()), duration: Some(Duration::from_nanos(1500)), status: RunStatus::Ok },
+            RunRecord::without_answer(7, Part::B, RunStatus::Unimplemented),
+            RunRecord::without_answer(8, Part::A, RunStatus::Error("Unable to find input file \"input_08.txt\", sorry".to_string())),
+            RunRecord::without_answer(9, Part::A, RunStatus::Crashed(Panic { message: "boom".to_string(), location: Some("src/problems/problem_09.rs:4:5".to_string()) })),
+        ]
+    }
+
+    struct MissingInput;
+
+    impl Solution for MissingInput {
+
+        fn day(&self) -> u32 {
+            99
+        }
+
+        fn input_kind(&self) -> InputKind {
+            InputKind::Lines
+        }
+
+        fn part_a(&self) -> Option<Solver> {
+            Some(|_| Answer::Unsigned(0))
+        }
+    }
+
+    struct Panicking;
+
+    impl Solution for Panicking {
+
+        fn day(&self) -> u32 {
+            98
+        }
+
+        fn input_kind(&self) -> InputKind {
+            InputKind::Lines
+        }
+
+        fn input_file(&self) -> String {
+            "example_01.txt".to_string()
+        }
+
+        fn part_a(&self) -> Option<Solver> {
+            Some(|input| panic!("{} lines is too many", input.into_lines().len()))
+        }
+    }
+
+    #[test]
+    fn test_catch_panic_records_message_and_location() {
+        assert_eq!(catch_panic(|| 1 + 1), Ok(2));
+
+        let panic = catch_panic(|| -> u32 { panic!("boom") }).unwrap_err();
+        assert_eq!(panic.message, "boom");
+        assert!(panic.location.as_deref().unwrap().starts_with(file!()));
+        assert!(!panic.is_unimplemented());
+
+        assert!(catch_panic(|| -> u32 { unimplemented!() }).unwrap_err().is_unimplemented());
+        assert!(catch_panic(|| -> u32 { todo!("part b") }).unwrap_err().is_unimplemented());
+        assert!(!catch_panic(|| -> u32 { panic!("Negative depths are not implemented") }).unwrap_err().is_unimplemented());
+    }
+
+    #[test]
+    fn test_only_unimplemented_panics_count_as_unimplemented() {
+        struct Unsupported;
+
+        impl Solution for Unsupported {
+
+            fn day(&self) -> u32 {
+                97
+            }
+
+            fn input_kind(&self) -> InputKind {
+                InputKind::Lines
+            }
+
+            fn input_file(&self) -> String {
+                "example_01.txt".to_string()
+            }
+
+            fn part_a(&self) -> Option<Solver> {
+                Some(|_| panic!("Negative depths are not implemented"))
+            }
+
+            fn part_b(&self) -> Option<Solver> {
+                Some(|_| unimplemented!())
+            }
+        }
+
+        let records = run_solution(&InputParser::new(), &Unsupported, &Part::both());
+        assert!(matches!(&records[0].status, RunStatus::Crashed(panic) if panic.message == "Negative depths are not implemented"));
+        assert_eq!(records[1].status, RunStatus::Unimplemented);
+    }
+
+    #[test]
+    fn test_run_solution_keeps_going_after_panics() {
+        let records = run_solution(&InputParser::new(), &Panicking, &Part::both());
+
+        match &records[0].status {
+            RunStatus::Crashed(panic) => {
+                assert_eq!(panic.message, "10 lines is too many");
+                assert!(panic.location.as_deref().unwrap().starts_with(file!()));
+            },
+            status => panic!("Expected a crash, got {:?}.", status),
+        }
+        assert_eq!(records[1].status, RunStatus::Unimplemented);
+    }
+
+    #[test]
+    fn test_run_solution_reports_every_part() {
+        let parser = InputParser::new();
+
+        let records = run_solution(&parser, problems::get_solution(1).unwrap().as_ref(), &Part::both());
+        assert!(records.iter().all(|record| record.status == RunStatus::Ok && record.answer.is_some() && record.duration.is_some()));
+
+        let records = run_solution(&parser, problems::get_solution(7).unwrap().as_ref(), &Part::both());
+        assert!(records.iter().all(|record| record.status == RunStatus::Unimplemented));
+    }
+
+    #[test]
+    fn test_run_input_reports_each_part_of_a_given_input() {
+        let solution = problems::get_solution(4).unwrap();
+        let input = solution.input_kind().load(&InputParser::new(), Source::text("7,4\n\n1 2\n3 4"));
+        let records = run_input(solution.as_ref(), input, &Part::both());
+
+        assert!(matches!(&records[0].status, RunStatus::Crashed(panic) if panic.message.contains("without bingo")));
+        assert_eq!(records.len(), 2);
+
+        let records = run_input(problems::get_solution(7).unwrap().as_ref(), Ok(Input::Lines(vec![])), &[Part::B]);
+        assert_eq!(records, vec![RunRecord::without_answer(7, Part::B, RunStatus::Unimplemented)]);
+
+        let lines = vec!["forward 5".to_string(), "sideways 2".to_string(), "up x".to_string()];
+        let records = run_input(problems::get_solution(2).unwrap().as_ref(), Ok(Input::Lines(lines)), &[Part::A]);
+        assert!(matches!(&records[0].status, RunStatus::Crashed(panic) if panic.message.contains("input:2:1") && panic.message.contains("input:3:1")));
+    }
+
+    #[test]
+    fn test_run_solution_reports_input_errors() {
+        let records = run_solution(&InputParser::new(), &MissingInput, &Part::both());
+
+        assert!(matches!(&records[0].status, RunStatus::Error(message) if message.contains("input_99.txt")));
+        assert_eq!(records[1].status, RunStatus::Unimplemented);
+    }
+
+    #[test]
+    fn test_json_lines_output() {
+        let output = format_records(&sample_records(), OutputFormat::JsonLines);
+        let lines: Vec<&str> = output.lines().collect();
+
+        assert_eq!(lines[0], "{\"day\":1,\"part\":\"a\",\"answer\":\"7\",\"duration_ns\":1500,\"status\":\"ok\",\"message\":null,\"location\":null}");
+        assert_eq!(lines[1], "{\"day\":7,\"part\":\"b\",\"answer\":null,\"duration_ns\":null,\"status\":\"unimplemented\",\"message\":null,\"location\":null}");
+        assert!(lines[2].ends_with("\"status\":\"error\",\"message\":\"Unable to find input file \\\"input_08.txt\\\", sorry\",\"location\":null}"));
+        assert!(lines[3].ends_with("\"status\":\"crashed\",\"message\":\"boom\",\"location\":\"src/problems/problem_09.rs:4:5\"}"));
+    }
+
+    #[test]
+    fn test_csv_output_quotes_fields() {
+        let output = format_records(&sample_records(), OutputFormat::Csv);
+        let lines: Vec<&str> = output.lines().collect();
+
+        assert_eq!(lines[0], "day,part,answer,duration_ns,status,message,location");
+        assert_eq!(lines[1], "1,a,7,1500,ok,,");
+        assert_eq!(lines[2], "7,b,,,unimplemented,,");
+        assert_eq!(lines[3], "8,a,,,error,\"Unable to find input file \"\"input_08.txt\"\", sorry\",");
+        assert_eq!(lines[4], "9,a,,,crashed,boom,src/problems/problem_09.rs:4:5");
+    }
+
+    #[test]
+    fn test_output_format_parses() {
+        assert_eq!(OutputFormat::parse("JSON").unwrap(), OutputFormat::JsonLines);
+        assert_eq!(OutputFormat::parse("csv").unwrap(), OutputFormat::Csv);
+        assert!(OutputFormat::parse("xml").is_err());
+    }
+}
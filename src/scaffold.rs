@@ -0,0 +1,318 @@
+use std::{fs, path::{Path, PathBuf}};
+
+use crate::answers::{Answers, ANSWERS_FILE};
+use crate::problems::solution::Part;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Template {
+    Integers,
+    Lines,
+    Grid,
+    Blocks,
+}
+
+impl Template {
+
+    pub fn parse(s: &str) -> Result<Self, String> {
+        match s.to_lowercase().as_str() {
+            "integers" => Ok(Self::Integers),
+            "lines" => Ok(Self::Lines),
+            "grid" => Ok(Self::Grid),
+            "blocks" => Ok(Self::Blocks),
+            _ => Err(format!("Invalid template: {}. Expected integers, lines, grid or blocks.", s)),
+        }
+    }
+
+    fn input_kind(&self) -> &'static str {
+        match self {
+            Self::Integers => "Integers",
+            Self::Lines => "Lines",
+            Self::Grid => "Grid",
+            Self::Blocks => "Blocks",
+        }
+    }
+
+    fn input_type(&self) -> &'static str {
+        match self {
+            Self::Integers => "Vec<i32>",
+            Self::Lines => "Vec<String>",
+            Self::Grid => "Grid<u8>",
+            Self::Blocks => "Vec<Vec<String>>",
+        }
+    }
+
+    fn accessor(&self) -> &'static str {
+        match self {
+            Self::Integers => "into_integers",
+            Self::Lines => "into_lines",
+            Self::Grid => "into_grid",
+            Self::Blocks => "into_blocks",
+        }
+    }
+
+    pub fn render(&self, day: u32) -> String {
+        let imports = match self {
+            Self::Grid => "use crate::input::grid::Grid;\n",
+            _ => "",
+        };
+        let mut solvers = String::new();
+        for part in Part::both() {
+            solvers.push_str(&format!(
+                "pub fn solve_problem_{day:02}{part}(_input: {input_type}) -> usize {{\n    unimplemented!();\n}}\n\n",
+                day = day, part = part.letter(), input_type = self.input_type(),
+            ));
+        }
+        format!(
+            "{imports}use crate::problems::solution::{{InputKind, Solution, Solver}};
+
+{solvers}pub struct Problem{day:02};
+
+impl Solution for Problem{day:02} {{
+
+    fn day(&self) -> u32 {{
+        {day}
+    }}
+
+    fn input_kind(&self) -> InputKind {{
+        InputKind::{kind}
+    }}
+
+    fn part_a(&self) -> Option<Solver> {{
+        Some(|input| solve_problem_{day:02}a(input.{accessor}()).into())
+    }}
+
+    fn part_b(&self) -> Option<Solver> {{
+        Some(|input| solve_problem_{day:02}b(input.{accessor}()).into())
+    }}
+}}
+",
+            imports = imports, solvers = solvers, day = day, kind = self.input_kind(), accessor = self.accessor(),
+        )
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Change {
+    Created(PathBuf),
+    Overwritten(PathBuf),
+    Updated(PathBuf),
+    Unchanged(PathBuf),
+}
+
+pub struct Scaffold {
+    problems_dir: PathBuf,
+    input_dir: PathBuf,
+}
+
+impl Scaffold {
+
+    pub fn new<P: AsRef<Path>, Q: AsRef<Path>>(problems_dir: P, input_dir: Q) -> Self {
+        Self { problems_dir: problems_dir.as_ref().to_path_buf(), input_dir: input_dir.as_ref().to_path_buf() }
+    }
+
+    pub fn default_problems_dir() -> PathBuf {
+        Path::new(env!("CARGO_MANIFEST_DIR")).join("src").join("problems")
+    }
+
+    pub fn module_path(&self, day: u32) -> PathBuf {
+        self.problems_dir.join(format!("problem_{:02}.rs", day))
+    }
+
+    pub fn generate(&self, day: u32, template: Template, force: bool) -> Result<Vec<Change>, String> {
+        if !(1..=25).contains(&day) {
+            return Err(format!("Invalid day: {}. Expected 1 to 25.", day));
+        }
+        Ok(vec![
+            self.write_module(day, template, force)?,
+            self.wire_module(day)?,
+            self.create_example(day)?,
+            self.add_answer_entries(day)?,
+        ])
+    }
+
+    fn write_module(&self, day: u32, template: Template, force: bool) -> Result<Change, String> {
+        let path = self.module_path(day);
+        let change = match fs::read_to_string(&path) {
+            Ok(existing) if !force && !is_untouched_stub(&existing, day) => return Err(format!(
+                "{} already has an implementation. Pass --force to replace it.",
+                path.display()
+            )),
+            Ok(_) => Change::Overwritten(path.clone()),
+            Err(_) => Change::Created(path.clone()),
+        };
+        write(&path, &template.render(day))?;
+        Ok(change)
+    }
+
+    fn wire_module(&self, day: u32) -> Result<Change, String> {
+        let path = self.problems_dir.join("mod.rs");
+        let source = fs::read_to_string(&path).map_err(|error| format!("Unable to read {}: {}.", path.display(), error))?;
+        let wired = wire_module(&source, day)?;
+        if wired == source {
+            return Ok(Change::Unchanged(path));
+        }
+        write(&path, &wired)?;
+        Ok(Change::Updated(path))
+    }
+
+    fn create_example(&self, day: u32) -> Result<Change, String> {
+        let path = self.input_dir.join(format!("example_{:02}.txt", day));
+        if path.exists() {
+            return Ok(Change::Unchanged(path));
+        }
+        write(&path, "")?;
+        Ok(Change::Created(path))
+    }
+
+    fn add_answer_entries(&self, day: u32) -> Result<Change, String> {
+        let path = self.input_dir.join(ANSWERS_FILE);
+        let existing = fs::read_to_string(&path).ok();
+        let answers = Answers::parse(existing.as_deref().unwrap_or_default()).map_err(|error| format!("{}:{}", path.display(), error))?;
+        let missing: Vec<Part> = Part::both().into_iter().filter(|part| answers.get(day, *part).is_none()).collect();
+        if missing.is_empty() {
+            return Ok(Change::Unchanged(path));
+        }
+
+        let mut text = existing.clone().unwrap_or_default();
+        for part in missing {
+            if !text.is_empty() && !text.ends_with("\n\n") {
+                text.push_str(if text.ends_with('\n') { "\n" } else { "\n\n" });
+            }
+            text.push_str(&format!("[[answer]]\nday = {}\npart = \"{}\"\nexample = \"\"\nreal = \"\"\n", day, part.letter()));
+        }
+        write(&path, &text)?;
+        Ok(match existing {
+            Some(_) => Change::Updated(path),
+            None => Change::Created(path),
+        })
+    }
+}
+
+fn write(path: &Path, contents: &str) -> Result<(), String> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).map_err(|error| format!("Unable to create {}: {}.", parent.display(), error))?;
+    }
+    fs::write(path, contents).map_err(|error| format!("Unable to write {}: {}.", path.display(), error))
+}
+
+fn solver_body<'a>(source: &'a str, name: &str) -> Option<&'a str> {
+    let start = source.find(&format!("fn {}(", name))?;
+    let open = start + source[start..].find('{')?;
+    let close = open + source[open..].find("\n}")?;
+    Some(source[open + 1..close].trim())
+}
+
+pub fn is_untouched_stub(source: &str, day: u32) -> bool {
+    Part::both().iter().all(|part| matches!(
+        solver_body(source, &format!("solve_problem_{:02}{}", day, part.letter())),
+        None | Some("unimplemented!();") | Some("unimplemented!()")
+    ))
+}
+
+pub fn wire_module(source: &str, day: u32) -> Result<String, String> {
+    let module = format!("problem_{:02}", day);
+    let declaration = format!("pub mod {};", module);
+    let entry = format!("        Box::new({}::Problem{:02}),", module, day);
+    let mut lines: Vec<String> = source.lines().map(|line| line.to_string()).collect();
+
+    if !lines.iter().any(|line| line.trim() == declaration) {
+        let declarations: Vec<usize> = (0..lines.len()).filter(|i| lines[*i].starts_with("pub mod ")).collect();
+        let position = declarations.iter().copied().find(|i| lines[*i].as_str() > declaration.as_str())
+            .or(declarations.last().map(|i| i + 1))
+            .unwrap_or(0);
+        lines.insert(position, declaration);
+    }
+
+    if !lines.iter().any(|line| line.trim() == entry.trim()) {
+        let start = lines.iter().position(|line| line.contains("fn all_solutions()")).ok_or("No all_solutions registry found.")?;
+        let end = start + lines[start..].iter().position(|line| line.trim() == "]").ok_or("Unterminated all_solutions registry.")?;
+        let position = (start..end).find(|i| lines[*i].trim().starts_with("Box::new(problem_") && lines[*i].as_str() > entry.as_str())
+            .unwrap_or(end);
+        lines.insert(position, entry);
+    }
+
+    let mut wired = lines.join("\n");
+    if source.ends_with('\n') {
+        wired.push('\n');
+    }
+    Ok(wired)
+}
+
+#[cfg(test)]
+mod test_scaffold {
+    use super::*;
+    use crate::scratch::ScratchDir;
+
+    const MODULES: &str = "pub mod problem_01;
+pub mod problem_03;
+pub mod solution;
+
+use solution::Solution;
+
+pub fn all_solutions() -> Vec<Box<dyn Solution>> {
+    vec![
+        Box::new(problem_01::Problem01),
+        Box::new(problem_03::Problem03),
+    ]
+}
+";
+
+    #[test]
+    fn test_wire_module_inserts_in_order_once() {
+        let wired = wire_module(MODULES, 2).unwrap();
+
+        assert!(wired.starts_with("pub mod problem_01;\npub mod problem_02;\npub mod problem_03;\n"));
+        assert!(wired.contains("Problem01),\n        Box::new(problem_02::Problem02),\n        Box::new(problem_03::Problem03),"));
+        assert_eq!(wire_module(&wired, 2).unwrap(), wired);
+
+        let wired = wire_module(MODULES, 4).unwrap();
+        assert!(wired.contains("pub mod problem_03;\npub mod problem_04;\npub mod solution;"));
+        assert!(wired.contains("Box::new(problem_04::Problem04),\n    ]"));
+    }
+
+    #[test]
+    fn test_untouched_stubs_are_recognised() {
+        assert!(is_untouched_stub(&Template::Grid.render(9), 9));
+        assert!(is_untouched_stub(&fs::read_to_string(Scaffold::default_problems_dir().join("problem_07.rs")).unwrap(), 7));
+        assert!(!is_untouched_stub(&fs::read_to_string(Scaffold::default_problems_dir().join("problem_01.rs")).unwrap(), 1));
+    }
+
+    #[test]
+    fn test_generate_writes_module_example_and_answers() {
+        let dir = ScratchDir::new("scaffold");
+        let scaffold = Scaffold::new(dir.join("problems"), dir.join("input"));
+        write(&dir.join("problems").join("mod.rs"), MODULES).unwrap();
+        write(&dir.join("input").join(ANSWERS_FILE), "[[answer]]\nday = 2\npart = \"a\"\nexample = \"150\"\n").unwrap();
+
+        let changes = scaffold.generate(2, Template::Blocks, false).unwrap();
+        assert_eq!(changes, vec![
+            Change::Created(dir.join("problems").join("problem_02.rs")),
+            Change::Updated(dir.join("problems").join("mod.rs")),
+            Change::Created(dir.join("input").join("example_02.txt")),
+            Change::Updated(dir.join("input").join(ANSWERS_FILE)),
+        ]);
+
+        let module = fs::read_to_string(scaffold.module_path(2)).unwrap();
+        assert!(module.contains("pub fn solve_problem_02a(_input: Vec<Vec<String>>) -> usize"));
+        assert!(module.contains("InputKind::Blocks"));
+        assert!(module.contains("Some(|input| solve_problem_02b(input.into_blocks()).into())"));
+        assert!(!module.contains("#[test]"));
+
+        let answers = Answers::parse(&fs::read_to_string(dir.join("input").join(ANSWERS_FILE)).unwrap()).unwrap();
+        assert_eq!(answers.get(2, Part::A).unwrap().example.as_deref(), Some("150"));
+        assert_eq!(answers.get(2, Part::B).unwrap().example.as_deref(), Some(""));
+    }
+
+    #[test]
+    fn test_generate_refuses_to_replace_implementations() {
+        let dir = ScratchDir::new("scaffold");
+        let scaffold = Scaffold::new(dir.join("problems"), dir.join("input"));
+        write(&dir.join("problems").join("mod.rs"), MODULES).unwrap();
+        write(&scaffold.module_path(3), &Template::Lines.render(3).replacen("unimplemented!();", "42", 1)).unwrap();
+
+        assert!(scaffold.generate(3, Template::Grid, false).unwrap_err().contains("--force"));
+        assert_eq!(scaffold.generate(3, Template::Grid, true).unwrap()[0], Change::Overwritten(scaffold.module_path(3)));
+        assert_eq!(scaffold.generate(3, Template::Integers, false).unwrap()[1], Change::Unchanged(dir.join("problems").join("mod.rs")));
+        assert!(scaffold.generate(26, Template::Lines, false).is_err());
+    }
+}
@@ -0,0 +1,39 @@
+use std::{env, fs, ops::Deref, path::{Path, PathBuf}, process, sync::atomic::{AtomicUsize, Ordering}};
+
+static NEXT_ID: AtomicUsize = AtomicUsize::new(0);
+
+pub struct ScratchDir {
+    path: PathBuf,
+}
+
+impl ScratchDir {
+
+    pub fn new(name: &str) -> Self {
+        let path = env::temp_dir().join(format!("aoc-{}-{}-{}", name, process::id(), NEXT_ID.fetch_add(1, Ordering::Relaxed)));
+        let _ = fs::remove_dir_all(&path);
+        fs::create_dir_all(&path).unwrap();
+        Self { path }
+    }
+}
+
+impl Deref for ScratchDir {
+    type Target = Path;
+
+    fn deref(&self) -> &Path {
+        &self.path
+    }
+}
+
+impl AsRef<Path> for ScratchDir {
+
+    fn as_ref(&self) -> &Path {
+        &self.path
+    }
+}
+
+impl Drop for ScratchDir {
+
+    fn drop(&mut self) {
+        let _ = fs::remove_dir_all(&self.path);
+    }
+}
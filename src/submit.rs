@@ -0,0 +1,342 @@
+use std::{fmt, fs, io::Write, path::{Path, PathBuf}, time::{SystemTime, UNIX_EPOCH}};
+
+use crate::http::{self, Url};
+use crate::input::fetch::YEAR;
+use crate::problems::solution::Part;
+
+pub const SUBMISSION_LOG: &str = "submissions.log";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    Wrong,
+    TooHigh,
+    TooLow,
+    RateLimited(Option<String>),
+    AlreadySolved,
+    Unknown(String),
+}
+
+impl Verdict {
+
+    pub fn parse(body: &str) -> Self {
+        let text = strip_tags(body);
+        if text.contains("That's the right answer") {
+            Self::Correct
+        } else if text.contains("your answer is too high") {
+            Self::TooHigh
+        } else if text.contains("your answer is too low") {
+            Self::TooLow
+        } else if text.contains("That's not the right answer") {
+            Self::Wrong
+        } else if text.contains("You gave an answer too recently") {
+            let wait = text.split_once("you have ")
+                .and_then(|(_, rest)| rest.split_once(" left to wait"))
+                .map(|(wait, _)| wait.to_string());
+            Self::RateLimited(wait)
+        } else if text.contains("You don't seem to be solving the right level") {
+            Self::AlreadySolved
+        } else {
+            Self::Unknown(text.split_whitespace().collect::<Vec<_>>().join(" "))
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Correct => "correct",
+            Self::Wrong => "wrong",
+            Self::TooHigh => "too-high",
+            Self::TooLow => "too-low",
+            Self::RateLimited(_) => "rate-limited",
+            Self::AlreadySolved => "already-solved",
+            Self::Unknown(_) => "unknown",
+        }
+    }
+
+    fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "correct" => Some(Self::Correct),
+            "wrong" => Some(Self::Wrong),
+            "too-high" => Some(Self::TooHigh),
+            "too-low" => Some(Self::TooLow),
+            "rate-limited" => Some(Self::RateLimited(None)),
+            "already-solved" => Some(Self::AlreadySolved),
+            "unknown" => Some(Self::Unknown(String::new())),
+            _ => None,
+        }
+    }
+
+    pub fn is_wrong(&self) -> bool {
+        matches!(self, Self::Wrong | Self::TooHigh | Self::TooLow)
+    }
+}
+
+impl fmt::Display for Verdict {
+
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Correct => write!(f, "That's the right answer!"),
+            Self::Wrong => write!(f, "That's not the right answer."),
+            Self::TooHigh => write!(f, "That's not the right answer; it is too high."),
+            Self::TooLow => write!(f, "That's not the right answer; it is too low."),
+            Self::RateLimited(Some(wait)) => write!(f, "Rate limited; {} left to wait.", wait),
+            Self::RateLimited(None) => write!(f, "Rate limited."),
+            Self::AlreadySolved => write!(f, "This part has already been solved."),
+            Self::Unknown(text) => write!(f, "Unrecognised response: {}", text),
+        }
+    }
+}
+
+fn strip_tags(html: &str) -> String {
+    let mut text = String::with_capacity(html.len());
+    let mut in_tag = false;
+    for c in html.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' if in_tag => in_tag = false,
+            c if !in_tag => text.push(c),
+            _ => {},
+        }
+    }
+    text
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Attempt {
+    pub timestamp: u64,
+    pub day: u32,
+    pub part: Part,
+    pub answer: String,
+    pub verdict: Verdict,
+}
+
+impl Attempt {
+
+    fn to_line(&self) -> String {
+        format!("{}\t{}\t{}\t{}\t{}", self.timestamp, self.day, self.part.letter(), self.verdict.name(), self.answer)
+    }
+
+    fn from_line(line: &str) -> Result<Self, String> {
+        let fields: Vec<&str> = line.splitn(5, '\t').collect();
+        if fields.len() != 5 {
+            return Err(format!("Expected 5 tab-separated fields, found {}.", fields.len()));
+        }
+        Ok(Self {
+            timestamp: fields[0].parse().map_err(|_| format!("Invalid timestamp: {}.", fields[0]))?,
+            day: fields[1].parse().map_err(|_| format!("Invalid day: {}.", fields[1]))?,
+            part: Part::parse(fields[2])?,
+            verdict: Verdict::from_name(fields[3]).ok_or(format!("Invalid verdict: {}.", fields[3]))?,
+            answer: fields[4].to_string(),
+        })
+    }
+}
+
+pub struct SubmissionLog {
+    path: PathBuf,
+    attempts: Vec<Attempt>,
+}
+
+impl SubmissionLog {
+
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self, String> {
+        let path = path.as_ref().to_path_buf();
+        let attempts = match fs::read_to_string(&path) {
+            Ok(text) => text.lines().enumerate()
+                .filter(|(_, line)| !line.trim().is_empty())
+                .map(|(i, line)| Attempt::from_line(line).map_err(|error| format!("{}:{}: {}", path.display(), i + 1, error)))
+                .collect::<Result<Vec<_>, _>>()?,
+            Err(error) if error.kind() == std::io::ErrorKind::NotFound => vec![],
+            Err(error) => return Err(format!("Unable to read {}: {}.", path.display(), error)),
+        };
+        Ok(Self { path, attempts })
+    }
+
+    pub fn attempts(&self, day: u32, part: Part) -> impl Iterator<Item = &Attempt> {
+        self.attempts.iter().filter(move |attempt| attempt.day == day && attempt.part == part)
+    }
+
+    pub fn check(&self, day: u32, part: Part, answer: &str) -> Result<(), String> {
+        if let Some(solved) = self.attempts(day, part).find(|attempt| attempt.verdict == Verdict::Correct) {
+            return Err(format!("Day {} part {} was already solved with {}.", day, part.letter(), solved.answer));
+        }
+        if let Some(previous) = self.attempts(day, part).find(|attempt| attempt.answer == answer && attempt.verdict.is_wrong()) {
+            return Err(format!("{} was already rejected as {}.", answer, previous.verdict.name()));
+        }
+        if let Ok(value) = answer.parse::<i128>() {
+            let bound = |verdict: Verdict| self.attempts(day, part)
+                .filter(move |attempt| attempt.verdict == verdict)
+                .filter_map(|attempt| attempt.answer.parse::<i128>().ok());
+            if let Some(high) = bound(Verdict::TooHigh).min().filter(|high| value >= *high) {
+                return Err(format!("{} cannot be right: {} was already too high.", answer, high));
+            }
+            if let Some(low) = bound(Verdict::TooLow).max().filter(|low| value <= *low) {
+                return Err(format!("{} cannot be right: {} was already too low.", answer, low));
+            }
+        }
+        Ok(())
+    }
+
+    pub fn record(&mut self, attempt: Attempt) -> Result<(), String> {
+        if let Some(parent) = self.path.parent() {
+            fs::create_dir_all(parent).map_err(|error| format!("Unable to create {}: {}.", parent.display(), error))?;
+        }
+        let mut file = fs::OpenOptions::new().create(true).append(true).open(&self.path)
+            .map_err(|error| format!("Unable to open {}: {}.", self.path.display(), error))?;
+        writeln!(file, "{}", attempt.to_line()).map_err(|error| format!("Unable to write {}: {}.", self.path.display(), error))?;
+        self.attempts.push(attempt);
+        Ok(())
+    }
+}
+
+pub struct Submitter {
+    base_url: Url,
+    session: String,
+    year: u32,
+    log: SubmissionLog,
+}
+
+impl Submitter {
+
+    pub fn new<P: AsRef<Path>>(base_url: &str, session: &str, log_path: P) -> Result<Self, String> {
+        Ok(Self {
+            base_url: Url::parse(base_url)?,
+            session: session.trim().to_string(),
+            year: YEAR,
+            log: SubmissionLog::load(log_path)?,
+        })
+    }
+
+    pub fn with_year(mut self, year: u32) -> Self {
+        self.year = year;
+        self
+    }
+
+    pub fn answer_url(&self, day: u32) -> Url {
+        self.base_url.join(&format!("{}/day/{}/answer", self.year, day))
+    }
+
+    pub fn log(&self) -> &SubmissionLog {
+        &self.log
+    }
+
+    pub fn submit(&mut self, day: u32, part: Part, answer: &str) -> Result<Verdict, String> {
+        let answer = answer.trim();
+        if answer.is_empty() || answer.contains(['\n', '\t']) {
+            return Err(format!("{:?} is not a submittable answer.", answer));
+        }
+        self.log.check(day, part, answer)?;
+
+        let cookie = format!("session={}", self.session);
+        let level = match part {
+            Part::A => "1",
+            Part::B => "2",
+        };
+        let response = http::post_form(
+            &self.answer_url(day),
+            &[("Cookie", &cookie), ("User-Agent", "advent-of-code-2021 answer submitter")],
+            &[("level", level), ("answer", answer)],
+        )?;
+        if !response.is_success() {
+            return Err(format!(
+                "Submitting day {} returned status {}: {}",
+                day, response.status, response.body.lines().next().unwrap_or_default()
+            ));
+        }
+
+        let verdict = Verdict::parse(&response.body);
+        let timestamp = SystemTime::now().duration_since(UNIX_EPOCH).map(|elapsed| elapsed.as_secs()).unwrap_or_default();
+        self.log.record(Attempt { timestamp, day, part, answer: answer.to_string(), verdict: verdict.clone() })?;
+        Ok(verdict)
+    }
+}
+
+#[cfg(test)]
+mod test_submit {
+    use super::*;
+    use crate::http::test_server::TestServer;
+    use crate::input::fetch::DEFAULT_BASE_URL;
+    use crate::scratch::ScratchDir;
+
+    fn article(text: &str) -> String {
+        format!("<html><body><main><article><p>{}</p></article></main></body></html>", text)
+    }
+
+    #[test]
+    fn test_verdicts_parse_from_responses() {
+        assert_eq!(Verdict::parse(&article("That's the right answer! You are <em>one gold star</em> closer.")), Verdict::Correct);
+        assert_eq!(Verdict::parse(&article("That's not the right answer; your answer is too high.")), Verdict::TooHigh);
+        assert_eq!(Verdict::parse(&article("That's not the right answer; your answer is too low.")), Verdict::TooLow);
+        assert_eq!(Verdict::parse(&article("That's not the right answer. If you're stuck...")), Verdict::Wrong);
+        assert_eq!(
+            Verdict::parse(&article("You gave an answer too recently; you have 42s left to wait.")),
+            Verdict::RateLimited(Some("42s".to_string()))
+        );
+        assert_eq!(Verdict::parse(&article("You don't seem to be solving the right level.")), Verdict::AlreadySolved);
+        assert_eq!(Verdict::parse("<p>Something\n  else</p>"), Verdict::Unknown("Something else".to_string()));
+    }
+
+    #[test]
+    fn test_submit_posts_and_logs_attempts() {
+        let server = TestServer::start(2, |request| match request.body.as_str() {
+            "level=1&answer=31584" => (200, article("That's not the right answer; your answer is too low.")),
+            _ => (200, article("That's the right answer!")),
+        });
+        let dir = ScratchDir::new("submit");
+        let log_path = dir.join(SUBMISSION_LOG);
+        let mut submitter = Submitter::new(&server.base_url, "secret", &log_path).unwrap();
+
+        assert_eq!(submitter.submit(4, Part::A, "31584").unwrap(), Verdict::TooLow);
+        assert_eq!(submitter.submit(4, Part::A, "87456\n").unwrap(), Verdict::Correct);
+
+        let requests = server.requests();
+        assert_eq!(requests[0].method, "POST");
+        assert_eq!(requests[0].path, "/2021/day/4/answer");
+        assert_eq!(requests[0].header("cookie"), Some("session=secret"));
+        assert_eq!(requests[1].body, "level=1&answer=87456");
+
+        let log = SubmissionLog::load(&log_path).unwrap();
+        let verdicts: Vec<Verdict> = log.attempts(4, Part::A).map(|attempt| attempt.verdict.clone()).collect();
+        assert_eq!(verdicts, vec![Verdict::TooLow, Verdict::Correct]);
+    }
+
+    #[test]
+    fn test_log_rejects_known_wrong_and_hopeless_answers() {
+        let dir = ScratchDir::new("submit");
+        let log_path = dir.join(SUBMISSION_LOG);
+        let mut log = SubmissionLog::load(&log_path).unwrap();
+        let attempt = |answer: &str, verdict: Verdict| Attempt { timestamp: 0, day: 4, part: Part::B, answer: answer.to_string(), verdict };
+        log.record(attempt("31584", Verdict::Wrong)).unwrap();
+        log.record(attempt("20000", Verdict::TooHigh)).unwrap();
+        log.record(attempt("10000", Verdict::TooLow)).unwrap();
+
+        assert!(log.check(4, Part::B, "31584").unwrap_err().contains("already rejected"));
+        assert!(log.check(4, Part::B, "25000").unwrap_err().contains("too high"));
+        assert!(log.check(4, Part::B, "9999").unwrap_err().contains("too low"));
+        assert!(log.check(4, Part::B, "15561").is_ok());
+        assert!(log.check(4, Part::A, "25000").is_ok());
+
+        let reloaded = SubmissionLog::load(&log_path).unwrap();
+        assert_eq!(reloaded.attempts(4, Part::B).count(), 3);
+    }
+
+    #[test]
+    fn test_submit_refuses_locally_without_posting() {
+        let server = TestServer::start(1, |_| (200, article("That's the right answer!")));
+        let dir = ScratchDir::new("submit");
+        let log_path = dir.join(SUBMISSION_LOG);
+        let mut submitter = Submitter::new(&server.base_url, "secret", &log_path).unwrap();
+
+        assert_eq!(submitter.submit(1, Part::B, "1589").unwrap(), Verdict::Correct);
+        assert!(submitter.submit(1, Part::B, "1590").unwrap_err().contains("already solved"));
+        assert_eq!(server.requests().len(), 1);
+    }
+
+    #[test]
+    fn test_submitter_accepts_the_default_endpoint() {
+        let dir = ScratchDir::new("submit");
+        let log_path = dir.join(SUBMISSION_LOG);
+        let submitter = Submitter::new(DEFAULT_BASE_URL, "secret", &log_path).unwrap().with_year(2020);
+
+        assert_eq!(submitter.answer_url(7).to_string(), "https://adventofcode.com/2020/day/7/answer");
+    }
+}
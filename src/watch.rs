@@ -0,0 +1,225 @@
+use std::{collections::BTreeMap, env, fs, path::{Path, PathBuf}, process::Command, thread, time::{Duration, SystemTime}};
+
+use crate::answers::Dataset;
+use crate::input::{input::InputParser, source::Source};
+use crate::problems::solution::{Part, Solution};
+use crate::runner::catch_panic;
+
+pub const POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Stamp {
+    modified: SystemTime,
+    len: u64,
+}
+
+pub struct FileWatcher {
+    files: Vec<(PathBuf, Option<Stamp>)>,
+}
+
+impl FileWatcher {
+
+    pub fn new(paths: Vec<PathBuf>) -> Self {
+        Self { files: paths.into_iter().map(|path| { let stamp = stamp(&path); (path, stamp) }).collect() }
+    }
+
+    pub fn changed(&mut self) -> Vec<PathBuf> {
+        let mut changed = vec![];
+        for (path, last) in self.files.iter_mut() {
+            let current = stamp(path);
+            if current != *last {
+                *last = current;
+                changed.push(path.clone());
+            }
+        }
+        changed
+    }
+}
+
+fn stamp(path: &Path) -> Option<Stamp> {
+    let metadata = fs::metadata(path).ok()?;
+    Some(Stamp { modified: metadata.modified().ok()?, len: metadata.len() })
+}
+
+pub type Results = BTreeMap<String, String>;
+
+pub fn evaluate(parser: &InputParser, solution: &dyn Solution) -> Results {
+    let mut results = Results::new();
+    for dataset in Dataset::both() {
+        let input = solution.input_kind().load(parser, Source::file(&dataset.input_file(solution.day())));
+        for part in Part::both() {
+            let label = format!("{} {}", dataset, part.letter());
+            let outcome = match &input {
+                _ if !solution.implemented(part) => "unimplemented".to_string(),
+                Err(error) => format!("error: {}", error),
+                Ok(input) => match catch_panic(|| solution.solve(part, input.clone()).to_string()) {
+                    Ok(answer) => answer,
+                    Err(panic) if panic.is_unimplemented() => "unimplemented".to_string(),
+                    Err(panic) => format!("crashed: {}", panic),
+                },
+            };
+            results.insert(label, outcome);
+        }
+    }
+    results
+}
+
+pub fn format_results(results: &Results) -> String {
+    results.iter().map(|(label, outcome)| format!("{}: {}", label, escape(outcome))).collect::<Vec<_>>().join("\n")
+}
+
+pub fn parse_results(text: &str) -> Results {
+    text.lines()
+        .filter_map(|line| line.split_once(": "))
+        .map(|(label, outcome)| (label.to_string(), unescape(outcome)))
+        .collect()
+}
+
+fn escape(s: &str) -> String {
+    s.replace('\\', "\\\\").replace('\n', "\\n")
+}
+
+fn unescape(s: &str) -> String {
+    let mut unescaped = String::with_capacity(s.len());
+    let mut chars = s.chars();
+    while let Some(c) = chars.next() {
+        match (c, chars.clone().next()) {
+            ('\\', Some('n')) => { unescaped.push('\n'); chars.next(); },
+            ('\\', Some('\\')) => { unescaped.push('\\'); chars.next(); },
+            (c, _) => unescaped.push(c),
+        }
+    }
+    unescaped
+}
+
+pub fn diff(previous: &Results, current: &Results) -> Vec<String> {
+    current.iter().map(|(label, outcome)| match previous.get(label) {
+        None => format!("{}: {}", label, escape(outcome)),
+        Some(last) if last == outcome => format!("{}: {} (unchanged)", label, escape(outcome)),
+        Some(last) => format!("{}: {} -> {}", label, escape(last), escape(outcome)),
+    }).collect()
+}
+
+pub struct Watch<'a> {
+    solution: &'a dyn Solution,
+    parser: InputParser,
+    input_dir: Option<String>,
+    source_path: PathBuf,
+    last: Results,
+    stale: bool,
+}
+
+impl<'a> Watch<'a> {
+
+    pub fn new<P: AsRef<Path>>(solution: &'a dyn Solution, input_dir: Option<String>, source_path: P) -> Self {
+        let parser = match &input_dir {
+            Some(dir) => InputParser::with_input_dir(dir),
+            None => InputParser::new(),
+        };
+        Self { solution, parser, input_dir, source_path: source_path.as_ref().to_path_buf(), last: Results::new(), stale: false }
+    }
+
+    pub fn watched_files(&self) -> Vec<PathBuf> {
+        let mut files: Vec<PathBuf> = Dataset::both().iter().map(|dataset| {
+            let file = dataset.input_file(self.solution.day());
+            self.parser.resolve(&file).unwrap_or(self.parser.cache_dir().join(file))
+        }).collect();
+        files.push(self.source_path.clone());
+        files
+    }
+
+    pub fn run(&mut self) -> ! {
+        let mut watcher = FileWatcher::new(self.watched_files());
+        println!("Watching day {:02}: {}", self.solution.day(), watcher.files.iter().map(|(path, _)| path.display().to_string()).collect::<Vec<_>>().join(", "));
+        self.refresh(false);
+        loop {
+            thread::sleep(POLL_INTERVAL);
+            let changed = watcher.changed();
+            if changed.is_empty() {
+                continue;
+            }
+            for path in &changed {
+                println!("\nChanged: {}", path.display());
+            }
+            self.refresh(changed.contains(&self.source_path));
+        }
+    }
+
+    fn refresh(&mut self, source_changed: bool) {
+        self.stale |= source_changed;
+        let results = if self.stale {
+            match self.rerun_with_cargo() {
+                Ok(results) => results,
+                Err(message) => {
+                    println!("{}", message);
+                    return;
+                },
+            }
+        } else {
+            evaluate(&self.parser, self.solution)
+        };
+        for line in diff(&self.last, &results) {
+            println!("{}", line);
+        }
+        self.last = results;
+    }
+
+    fn rerun_with_cargo(&self) -> Result<Results, String> {
+        let mut command = Command::new(env::var("CARGO").unwrap_or("cargo".to_string()));
+        command.current_dir(env!("CARGO_MANIFEST_DIR"))
+            .args(["run", "--quiet", "--", "watch", "--once", "--day", &self.solution.day().to_string()]);
+        if let Some(dir) = &self.input_dir {
+            command.args(["--input-dir", dir]);
+        }
+        let output = command.output().map_err(|error| format!("Unable to run cargo: {}.", error))?;
+        if !output.status.success() {
+            return Err(format!("Rebuild failed:\n{}", String::from_utf8_lossy(&output.stderr).trim_end()));
+        }
+        Ok(parse_results(&String::from_utf8_lossy(&output.stdout)))
+    }
+}
+
+#[cfg(test)]
+mod test_watch {
+    use super::*;
+    use crate::problems;
+    use crate::scratch::ScratchDir;
+
+    #[test]
+    fn test_file_watcher_reports_changes_once() {
+        let dir = ScratchDir::new("watch");
+        let path = dir.join("input_01.txt");
+        let mut watcher = FileWatcher::new(vec![path.clone()]);
+
+        assert!(watcher.changed().is_empty());
+        fs::write(&path, "199\n").unwrap();
+        assert_eq!(watcher.changed(), vec![path.clone()]);
+        assert!(watcher.changed().is_empty());
+        fs::write(&path, "199\n200\n").unwrap();
+        assert_eq!(watcher.changed(), vec![path.clone()]);
+    }
+
+    #[test]
+    fn test_evaluate_covers_example_and_real_inputs() {
+        let results = evaluate(&InputParser::new(), problems::get_solution(1).unwrap().as_ref());
+        assert_eq!(results.get("example a").map(String::as_str), Some("7"));
+        assert_eq!(results.get("example b").map(String::as_str), Some("5"));
+        assert_eq!(results.len(), 4);
+
+        let results = evaluate(&InputParser::new(), problems::get_solution(7).unwrap().as_ref());
+        assert!(results.values().all(|outcome| outcome == "unimplemented"));
+    }
+
+    #[test]
+    fn test_results_round_trip_and_diff() {
+        let previous: Results = [("example a", "7"), ("real a", "1548")].iter().map(|(k, v)| (k.to_string(), v.to_string())).collect();
+        let current: Results = [("example a", "7"), ("real a", "#.\n.#"), ("real b", "5")].iter().map(|(k, v)| (k.to_string(), v.to_string())).collect();
+
+        assert_eq!(parse_results(&format_results(&current)), current);
+        assert_eq!(diff(&previous, &current), vec![
+            "example a: 7 (unchanged)".to_string(),
+            "real a: 1548 -> #.\\n.#".to_string(),
+            "real b: 5".to_string(),
+        ]);
+    }
+}
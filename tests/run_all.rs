@@ -0,0 +1,18 @@
+use std::process::Command;
+
+#[test]
+fn test_run_all_json_output_contains_only_records() {
+    let output = Command::new(env!("CARGO_BIN_EXE_advent-of-code-2021"))
+        .current_dir(env!("CARGO_MANIFEST_DIR"))
+        .args(["run-all", "--format", "json"])
+        .output()
+        .unwrap();
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    let lines: Vec<&str> = stdout.lines().collect();
+
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+    assert_eq!(lines.len(), 50, "{}", stdout);
+    for line in lines {
+        assert!(line.starts_with("{\"day\":") && line.ends_with('}'), "Unexpected line in JSON output: {:?}", line);
+    }
+}